@@ -0,0 +1,48 @@
+//! Benchmarks for parsing a large VPT into program views.
+//!
+//! Run with `cargo bench --features builder`.
+
+use std::borrow::Cow;
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use venice_program_table::{ProgramBuilder, ProgramKind, Vpt, VptBuf, VptBuilder};
+
+const PROGRAM_COUNT: u32 = 1000;
+
+fn thousand_program_vpt() -> VptBuf {
+    let mut builder = VptBuilder::with_capacity(0, PROGRAM_COUNT as usize);
+    for i in 0..PROGRAM_COUNT {
+        builder.add_program(ProgramBuilder {
+            name: Cow::Owned(format!("program/{i}").into_bytes()),
+            payload: Cow::Owned(vec![i as u8; 64]),
+            kind: ProgramKind::Data,
+            vendor_id: None,
+            payload_align: 1,
+            metadata: None,
+        });
+    }
+
+    // `Vec<u8>` carries no alignment guarantee, so copy into aligned storage to parse
+    Vpt::new_aligned(&builder.build(), 0).unwrap()
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let buf = thousand_program_vpt();
+    let vpt = buf.borrow();
+
+    c.bench_function("collect_programs", |b| {
+        b.iter(|| black_box(black_box(&vpt).collect_programs()));
+    });
+
+    c.bench_function("iter_collect", |b| {
+        b.iter(|| black_box(black_box(&vpt).program_iter().collect::<Vec<_>>()));
+    });
+
+    c.bench_function("program_by_name_last", |b| {
+        b.iter(|| black_box(black_box(&vpt).program_by_name(b"program/999")));
+    });
+}
+
+criterion_group!(benches, bench_parse);
+criterion_main!(benches);
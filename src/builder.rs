@@ -3,92 +3,816 @@
 //! This module can be used by enabling the `builder` feature, with the condition that a memory
 //! allocator is available to the program.
 
-use alloc::vec::Vec;
+use alloc::{borrow::Cow, vec::Vec};
 
-use crate::{ProgramHeader, SDK_VERSION, VPT_MAGIC, VptHeader, align8};
+use crate::{
+    ALIGN_16_HEADER_REM, BuildError, Compression, ProgramHeader, ProgramKind, SDK_VERSION,
+    VPT_MAGIC, Vpt, VptFlags, VptHeader, align8, crc32::crc32,
+};
 
 /// VPT program builder.
 ///
-/// This struct can be passed to [`VptBuilder::add_program`].
+/// This struct can be passed to [`VptBuilder::add_program`]. Its fields are [`Cow`]s, so a
+/// program assembled from bytes that already live for the build's duration — memory-mapped
+/// source files, static data — can borrow them instead of copying into an intermediate
+/// [`Vec<u8>`]; `build` copies borrowed bytes directly into the output buffer.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ProgramBuilder {
+pub struct ProgramBuilder<'a> {
     /// Name of the program.
-    pub name: Vec<u8>,
+    pub name: Cow<'a, [u8]>,
     /// Payload of the program.
-    pub payload: Vec<u8>,
+    pub payload: Cow<'a, [u8]>,
+    /// Kind of module the payload carries.
+    pub kind: ProgramKind,
+    /// Vendor that owns the program, or [`None`] to inherit the VPT's vendor. Only recorded in
+    /// the blob when the builder's flags include [`VptFlags::PROGRAM_VENDORS`].
+    pub vendor_id: Option<u32>,
+    /// Required absolute alignment of the payload within the blob, a power of two. Values of 0
+    /// or 1 request no alignment beyond the format's natural placement. Alignments above that
+    /// are recorded as pre-payload padding under [`VptFlags::PAYLOAD_PREPAD`] — as
+    /// execute-in-place payloads may require.
+    pub payload_align: u32,
+    /// Optional human-readable metadata string — a menu description, say — emitted after the
+    /// program's name under [`VptFlags::PROGRAM_METADATA`], which the builder sets when any
+    /// program carries metadata.
+    pub metadata: Option<Cow<'a, [u8]>>,
 }
 
 /// VPT builder.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct VptBuilder {
+pub struct VptBuilder<'a> {
     vendor_id: u32,
-    programs: Vec<ProgramBuilder>,
+    flags: VptFlags,
+    compression: Compression,
+    signature: Option<&'a [u8]>,
+    programs: Vec<ProgramBuilder<'a>>,
 }
 
-impl ProgramBuilder {
+impl<'a> ProgramBuilder<'a> {
+    /// Constructs a program with the given name and payload, of kind [`ProgramKind::Data`] and
+    /// with no vendor of its own.
+    ///
+    /// Both arguments accept anything convertible into a [`Cow`] — `&[u8]`, [`Vec<u8>`], or a
+    /// `Cow` itself — so callers need not spell out the struct literal. The fields stay public
+    /// for direct access.
+    pub fn new(name: impl Into<Cow<'a, [u8]>>, payload: impl Into<Cow<'a, [u8]>>) -> Self {
+        Self {
+            name: name.into(),
+            payload: payload.into(),
+            kind: ProgramKind::Data,
+            vendor_id: None,
+            payload_align: 1,
+            metadata: None,
+        }
+    }
+
+    /// Replaces the program's name, returning the builder for chaining.
+    #[must_use]
+    pub fn with_name(mut self, name: impl Into<Cow<'a, [u8]>>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Replaces the program's payload, returning the builder for chaining.
+    #[must_use]
+    pub fn with_payload(mut self, payload: impl Into<Cow<'a, [u8]>>) -> Self {
+        self.payload = payload.into();
+        self
+    }
+
+    /// Replaces the program's kind, returning the builder for chaining.
+    #[must_use]
+    pub fn with_kind(mut self, kind: ProgramKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Gives the program its own vendor, returning the builder for chaining; see
+    /// [`VptFlags::PROGRAM_VENDORS`].
+    #[must_use]
+    pub fn with_vendor(mut self, vendor_id: u32) -> Self {
+        self.vendor_id = Some(vendor_id);
+        self
+    }
+
+    /// Requires the payload to sit at a multiple of `payload_align` bytes within the blob,
+    /// returning the builder for chaining; see the [`payload_align`] field.
+    ///
+    /// [`payload_align`]: `ProgramBuilder::payload_align`
+    #[must_use]
+    pub fn with_payload_align(mut self, payload_align: u32) -> Self {
+        self.payload_align = payload_align;
+        self
+    }
+
+    /// Gives the program a metadata string, returning the builder for chaining; see the
+    /// [`metadata`] field.
+    ///
+    /// [`metadata`]: `ProgramBuilder::metadata`
+    #[must_use]
+    pub fn with_metadata(mut self, metadata: impl Into<Cow<'a, [u8]>>) -> Self {
+        self.metadata = Some(metadata.into());
+        self
+    }
+}
+
+/// Borrows a parsed program's name and payload into a builder, bridging the read and write
+/// sides: pull one [`Program`] out of a table, adjust it, and add it to a [`VptBuilder`] —
+/// without rebuilding everything through [`VptBuilder::from_vpt`].
+///
+/// The builder borrows the program's bytes from the VPT's blob, so nothing is copied until
+/// `build`. Like [`VptBuilder::from_vpt`], unknown kinds degrade to [`ProgramKind::Data`] and
+/// payloads are taken as stored.
+///
+/// [`Program`]: `crate::Program`
+impl<'a> From<crate::Program<'a>> for ProgramBuilder<'a> {
+    fn from(program: crate::Program<'a>) -> Self {
+        Self {
+            name: Cow::Borrowed(program.name()),
+            payload: Cow::Borrowed(program.payload()),
+            kind: program.kind().unwrap_or(ProgramKind::Data),
+            vendor_id: program.vendor_id(),
+            payload_align: 1,
+            metadata: program.metadata().map(Cow::Borrowed),
+        }
+    }
+}
+
+impl ProgramBuilder<'_> {
     /// Returns the size of the program without padding.
-    pub const fn base_size(&self) -> usize {
-        size_of::<ProgramHeader>() + self.name.len() + self.payload.len()
+    pub fn base_size(&self) -> usize {
+        size_of::<ProgramHeader>() + self.name.len() + self.payload.len() + self.meta_len()
     }
 
     /// Returns the size of the program with padding.
-    pub const fn size(&self) -> usize {
+    pub fn size(&self) -> usize {
         align8(self.base_size())
     }
 
     /// Returns the amount of padding bytes to be appended to the program.
-    pub const fn padding_bytes(&self) -> usize {
+    pub fn padding_bytes(&self) -> usize {
         self.size() - self.base_size()
     }
+
+    /// Returns the length of the program's metadata string in bytes, or 0 if it has none.
+    fn meta_len(&self) -> usize {
+        self.metadata.as_ref().map_or(0, |metadata| metadata.len())
+    }
 }
 
-impl VptBuilder {
+impl<'a> VptBuilder<'a> {
     /// Constructs a new builder with the provided vendor ID.
     pub const fn new(vendor_id: u32) -> Self {
         Self {
             vendor_id,
+            flags: VptFlags::empty(),
+            compression: Compression::None,
+            signature: None,
             programs: Vec::new(),
         }
     }
 
+    /// Constructs a new builder with the provided vendor ID and room for `capacity` programs.
+    pub fn with_capacity(vendor_id: u32, capacity: usize) -> Self {
+        Self {
+            vendor_id,
+            flags: VptFlags::empty(),
+            compression: Compression::None,
+            signature: None,
+            programs: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Constructs a builder containing every program of an existing parsed VPT.
+    ///
+    /// Programs borrow their names and payloads from the VPT's blob, so no bytes are copied
+    /// until `build`. This makes editing a round trip: parse, `from_vpt`, add or replace
+    /// programs, rebuild.
+    pub fn from_vpt(vpt: &Vpt<'a>, vendor_id: u32) -> Self {
+        let mut builder = Self::with_capacity(vendor_id, vpt.len() as usize);
+        for program in vpt {
+            builder.add_program(ProgramBuilder::from(program));
+        }
+        builder
+    }
+
+    /// Reserves room for at least `additional` more programs.
+    pub fn reserve(&mut self, additional: usize) {
+        self.programs.reserve(additional);
+    }
+
+    /// Sets the flags to be emitted in the VPT's header.
+    pub const fn set_flags(&mut self, flags: VptFlags) {
+        self.flags = flags;
+    }
+
+    /// Sets the compression codec applied to every program's payload at build time.
+    ///
+    /// The emitted program headers record the codec and each payload's uncompressed length, which
+    /// readers use for [`Program::decompress_into`].
+    ///
+    /// [`Program::decompress_into`]: `crate::Program::decompress_into`
+    pub const fn set_compression(&mut self, compression: Compression) {
+        self.compression = compression;
+    }
+
+    /// Sets a signature block to be appended after the table's `header.size` bytes.
+    ///
+    /// The emitted header records the block's length in `signature_len` and has
+    /// [`VptFlags::SIGNED`] set. The builder is crypto-agnostic: the caller signs
+    /// [`Vpt::signed_bytes`] with their chosen algorithm and provides the raw signature here.
+    ///
+    /// [`Vpt::signed_bytes`]: `crate::Vpt::signed_bytes`
+    pub const fn set_signature(&mut self, signature: &'a [u8]) {
+        self.signature = Some(signature);
+    }
+
     /// Adds a program to the VPT to be built.
-    pub fn add_program(&mut self, program: ProgramBuilder) {
+    pub fn add_program(&mut self, program: ProgramBuilder<'a>) -> &mut Self {
         self.programs.push(program);
+        self
+    }
+
+    /// Inserts a program at `index`, shifting the programs after it.
+    ///
+    /// Indices refer to insertion order, which is also iteration order in the built VPT, so this
+    /// controls where the program appears when the table is walked. Combined with [`from_vpt`],
+    /// it gives round-trip editing positional control.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of programs.
+    ///
+    /// [`from_vpt`]: `VptBuilder::from_vpt`
+    pub fn insert_program(&mut self, index: usize, program: ProgramBuilder<'a>) -> &mut Self {
+        self.programs.insert(index, program);
+        self
+    }
+
+    /// Removes and returns the program at `index`, or [`None`] if `index` is out of bounds.
+    pub fn remove_program(&mut self, index: usize) -> Option<ProgramBuilder<'a>> {
+        if index < self.programs.len() {
+            Some(self.programs.remove(index))
+        } else {
+            None
+        }
+    }
+
+    /// Adds a program to the VPT to be built, taking and returning the builder by value for a
+    /// fully fluent style.
+    #[must_use]
+    pub fn with_program(mut self, program: ProgramBuilder<'a>) -> Self {
+        self.programs.push(program);
+        self
+    }
+
+    /// Builds the VPT like [`build`], sorting programs by name so that readers can binary-search
+    /// the table. The emitted header has [`VptFlags::NAME_SORTED`] set, which
+    /// [`Vpt::program_by_name_sorted`] uses to decide between binary and linear search.
+    ///
+    /// [`build`]: `VptBuilder::build`
+    /// [`Vpt::program_by_name_sorted`]: `crate::Vpt::program_by_name_sorted`
+    pub fn build_sorted(mut self) -> Vec<u8> {
+        self.programs.sort_by(|a, b| a.name.cmp(&b.name));
+        self.flags |= VptFlags::NAME_SORTED;
+        self.build()
+    }
+
+    /// Builds the VPT like [`build`], storing byte-identical payloads once and having duplicate
+    /// programs reference the shared copy via `ProgramHeader::payload_offset`. The emitted
+    /// header has [`VptFlags::PAYLOAD_SHARED`] set; readers resolve the references
+    /// transparently, so [`Program::payload`] is unaffected.
+    ///
+    /// When many programs carry the same bytes — the same shared library linked by several
+    /// modules, say — this shrinks the blob by one payload copy per duplicate. Duplicate
+    /// detection compares the bytes actually emitted, so it applies after compression. Empty
+    /// payloads are always stored inline, since a zero `payload_offset` means "no reference".
+    ///
+    /// [`build`]: `VptBuilder::build`
+    /// [`Program::payload`]: `crate::Program::payload`
+    pub fn build_deduped(mut self) -> Vec<u8> {
+        self.flags |= VptFlags::PAYLOAD_SHARED;
+        self.build()
+    }
+
+    /// Checks that no two programs in the builder share a name.
+    ///
+    /// The check compares every pair of names, which is O(n²) but fine for the small tables VPTs
+    /// are built for.
+    ///
+    /// # Errors
+    ///
+    /// - [`BuildError::DuplicateName`] if two programs share a name.
+    pub fn check_unique_names(&self) -> Result<(), BuildError> {
+        for (i, program) in self.programs.iter().enumerate() {
+            if self.programs[..i].iter().any(|p| p.name == program.name) {
+                return Err(BuildError::DuplicateName {
+                    name: program.name.to_vec(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Builds the VPT like [`build`], rejecting tables in which two programs share a name.
+    ///
+    /// # Errors
+    ///
+    /// - [`BuildError::DuplicateName`] if two programs share a name.
+    ///
+    /// [`build`]: `VptBuilder::build`
+    pub fn build_unique(self) -> Result<Vec<u8>, BuildError> {
+        self.check_unique_names()?;
+        Ok(self.build())
+    }
+
+    /// Builds the VPT like [`build`], verifying that every size field fits in a `u32` before
+    /// casting and that no program's name is empty.
+    ///
+    /// An empty name is almost always a field left unset before the program was added: it makes
+    /// name-based lookup ambiguous and displays as nothing. The infallible [`build`] still emits
+    /// it for callers that genuinely want anonymous programs.
+    ///
+    /// # Errors
+    ///
+    /// - [`BuildError::EmptyName`] if a program's name is empty.
+    /// - [`BuildError::SizeOverflow`] if a program's name or payload length, or the total VPT
+    ///   size, exceeds [`u32::MAX`].
+    /// - [`BuildError::TooManyPrograms`] if the number of programs exceeds [`u32::MAX`].
+    ///
+    /// [`build`]: `VptBuilder::build`
+    pub fn build_checked(self) -> Result<Vec<u8>, BuildError> {
+        if u32::try_from(self.programs.len()).is_err() {
+            return Err(BuildError::TooManyPrograms {
+                count: self.programs.len(),
+            });
+        }
+
+        for (index, program) in self.programs.iter().enumerate() {
+            if program.name.is_empty() {
+                return Err(BuildError::EmptyName { index });
+            }
+            if u32::try_from(program.name.len()).is_err() {
+                return Err(BuildError::SizeOverflow {
+                    size: program.name.len(),
+                });
+            }
+            if u32::try_from(program.payload.len()).is_err() {
+                return Err(BuildError::SizeOverflow {
+                    size: program.payload.len(),
+                });
+            }
+            if u32::try_from(program.meta_len()).is_err() {
+                return Err(BuildError::SizeOverflow {
+                    size: program.meta_len(),
+                });
+            }
+        }
+
+        // measure the exact layout `build` will emit — compression, pre-payload padding,
+        // `PAYLOAD_ALIGN_16` inter-program padding, shared payloads, and the name-table region
+        // all count toward `header.size`; `layout` saturates rather than wraps on 32-bit
+        // targets, so an overflowing table cannot sneak under the check
+        let total_size = self.layout(&self.emitted_payloads()).total_size;
+        if u32::try_from(total_size).is_err() {
+            return Err(BuildError::SizeOverflow { size: total_size });
+        }
+
+        Ok(self.build())
     }
 
     /// Builds the VPT with the provided vendor ID, the SDK's version, and the programs added to the
     /// builder, as a [`Vec<u8>`].
+    ///
+    /// The output is deterministic: identical builder inputs produce byte-identical blobs, and
+    /// every padding byte is zero. Content-addressed artifact stores and build caches may rely
+    /// on this.
+    ///
+    /// Size fields are cast to `u32` without bounds checks, so a name, payload, or total size
+    /// exceeding [`u32::MAX`] is silently truncated in the emitted headers; on 32-bit targets
+    /// the total additionally saturates at [`usize::MAX`] instead of wrapping. Use
+    /// [`build_checked`] to reject such programs instead.
+    ///
+    /// [`build_checked`]: `VptBuilder::build_checked`
     pub fn build(self) -> Vec<u8> {
-        let total_size = size_of::<VptHeader>()
-            + self
+        let mut bytes = Vec::new();
+        self.build_into(&mut bytes);
+        bytes
+    }
+
+    /// Builds the VPT like [`build`], appending its bytes to `buf` instead of returning a new
+    /// [`Vec<u8>`].
+    ///
+    /// The builder is not consumed, so a long-running tool can reuse both the builder — see
+    /// [`clear`] — and the output allocation across many builds.
+    ///
+    /// [`build`]: `VptBuilder::build`
+    /// [`clear`]: `VptBuilder::clear`
+    pub fn build_into(&self, buf: &mut Vec<u8>) {
+        let payload_aligned = self.flags.contains(VptFlags::PAYLOAD_ALIGN_16);
+        let interned_names = self.flags.contains(VptFlags::NAME_TABLE);
+
+        let payloads = self.emitted_payloads();
+        let Layout {
+            name_table,
+            name_offsets,
+            prepads,
+            payload_offsets,
+            total_size,
+        } = self.layout(&payloads);
+
+        let start = buf.len();
+        buf.reserve(total_size);
+
+        let mut flags = self.flags;
+        if self.signature.is_some() {
+            flags |= VptFlags::SIGNED;
+        }
+        if self.programs.iter().any(|p| p.payload_align > 1) {
+            flags |= VptFlags::PAYLOAD_PREPAD;
+        }
+        if self.programs.iter().any(|p| p.metadata.is_some()) {
+            flags |= VptFlags::PROGRAM_METADATA;
+        }
+
+        buf.extend_from_slice(bytemuck::bytes_of(
+            &VptHeader {
+                magic: VPT_MAGIC,
+                version: SDK_VERSION,
+                vendor_id: self.vendor_id,
+                size: total_size as u32,
+                program_count: self.programs.len() as u32,
+                // patched once the payload has been written
+                checksum: 0,
+                flags: flags.bits(),
+                signature_len: self.signature.map_or(0, |s| s.len() as u32),
+                name_table_len: name_table.len() as u32,
+            }
+            .to_wire(),
+        ));
+
+        for (i, (program, payload)) in self.programs.iter().zip(payloads.iter()).enumerate() {
+            if payload_aligned && (buf.len() - start) % 16 != ALIGN_16_HEADER_REM {
+                buf.resize(buf.len() + 8, 0);
+            }
+
+            buf.extend_from_slice(bytemuck::bytes_of(
+                &ProgramHeader {
+                    name_len: program.name.len() as u32,
+                    payload_len: payload.len() as u32,
+                    compression: self.compression.as_raw(),
+                    uncompressed_len: program.payload.len() as u32,
+                    kind: program.kind.as_raw(),
+                    payload_digest: if self.flags.contains(VptFlags::PAYLOAD_DIGEST) {
+                        crc32(payload)
+                    } else {
+                        0
+                    },
+                    vendor_id: if self.flags.contains(VptFlags::PROGRAM_VENDORS) {
+                        program.vendor_id.unwrap_or(0)
+                    } else {
+                        0
+                    },
+                    name_offset: if interned_names { name_offsets[i] } else { 0 },
+                    payload_prepad: prepads[i] as u32,
+                    payload_offset: payload_offsets[i],
+                    meta_len: program.meta_len() as u32,
+                    reserved: 0,
+                }
+                .to_wire(),
+            ));
+
+            // a program referencing a shared payload emits no payload bytes of its own
+            let emitted_payload: &[u8] = if payload_offsets[i] == 0 {
+                payload
+            } else {
+                &[]
+            };
+
+            buf.resize(buf.len() + prepads[i], 0);
+            buf.extend_from_slice(emitted_payload);
+            let mut base_size = size_of::<ProgramHeader>() + prepads[i] + emitted_payload.len();
+            if !interned_names {
+                buf.extend_from_slice(&program.name);
+                base_size += program.name.len();
+            }
+            if let Some(metadata) = &program.metadata {
+                buf.extend_from_slice(metadata);
+                base_size += metadata.len();
+            }
+
+            // add padding
+            buf.resize(
+                buf.len() + self.emitted_size(program, emitted_payload, prepads[i]) - base_size,
+                0,
+            );
+        }
+
+        // the name table fills the last `name_table_len` bytes of the table region
+        buf.extend_from_slice(&name_table);
+
+        // `buf` is only 1-aligned, so the checksum is patched bytewise rather than through a
+        // `&mut VptHeader`.
+        let checksum = crc32(&buf[start + size_of::<VptHeader>()..]);
+        let offset = start + core::mem::offset_of!(VptHeader, checksum);
+        buf[offset..offset + size_of::<u32>()].copy_from_slice(&checksum.to_le_bytes());
+
+        // the signature trails the table's `header.size` bytes and is not covered by the
+        // checksum, which only spans the table
+        if let Some(signature) = self.signature {
+            buf.extend_from_slice(signature);
+        }
+    }
+
+    // Resolves the payload bytes actually emitted for each program under the builder's
+    // compression setting.
+    fn emitted_payloads(&self) -> Vec<Cow<'_, [u8]>> {
+        match self.compression {
+            Compression::None => self
+                .programs
+                .iter()
+                .map(|program| Cow::Borrowed(&*program.payload))
+                .collect(),
+            #[cfg(feature = "lz4")]
+            Compression::Lz4 => self
                 .programs
                 .iter()
-                .map(ProgramBuilder::size)
-                .sum::<usize>();
+                .map(|program| Cow::Owned(lz4_flex::block::compress(&program.payload)))
+                .collect(),
+        }
+    }
 
-        let mut bytes = Vec::with_capacity(total_size);
+    // Size a program occupies as emitted — header, pre-payload padding, inline payload and
+    // name, metadata — including its trailing `align8` padding.
+    fn emitted_size(&self, program: &ProgramBuilder<'_>, payload: &[u8], prepad: usize) -> usize {
+        let inline_name_len = if self.flags.contains(VptFlags::NAME_TABLE) {
+            0
+        } else {
+            program.name.len()
+        };
+        align8(
+            size_of::<ProgramHeader>()
+                + prepad
+                + inline_name_len
+                + payload.len()
+                + program.meta_len(),
+        )
+    }
 
-        bytes.extend_from_slice(bytemuck::bytes_of(&VptHeader {
-            magic: VPT_MAGIC,
-            version: SDK_VERSION,
-            vendor_id: self.vendor_id,
-            size: total_size as u32,
-            program_count: self.programs.len() as u32,
-        }));
+    // Lays the table out without emitting it. `build_into` and `build_checked` both derive
+    // their sizes from this one pass, so the checked total is exactly what gets emitted.
+    fn layout(&self, payloads: &[Cow<'_, [u8]>]) -> Layout {
+        let payload_aligned = self.flags.contains(VptFlags::PAYLOAD_ALIGN_16);
+        let interned_names = self.flags.contains(VptFlags::NAME_TABLE);
 
-        for program in self.programs.iter() {
-            bytes.extend_from_slice(bytemuck::bytes_of(&ProgramHeader {
-                name_len: program.name.len() as u32,
-                payload_len: program.payload.len() as u32,
-            }));
+        // under `NAME_TABLE`, names are interned into one shared region at the end of the table:
+        // each distinct name is stored once, and programs sharing a name share its offset
+        let mut name_table: Vec<u8> = Vec::new();
+        let mut name_offsets: Vec<u32> = Vec::new();
+        if interned_names {
+            name_offsets.reserve(self.programs.len());
+            for (i, program) in self.programs.iter().enumerate() {
+                let offset = match self.programs[..i]
+                    .iter()
+                    .position(|p| p.name == program.name)
+                {
+                    Some(j) => name_offsets[j],
+                    None => {
+                        let offset = name_table.len() as u32;
+                        name_table.extend_from_slice(&program.name);
+                        offset
+                    }
+                };
+                name_offsets.push(offset);
+            }
+        }
 
-            bytes.extend_from_slice(&program.payload);
-            bytes.extend_from_slice(&program.name);
+        let shared_payloads = self.flags.contains(VptFlags::PAYLOAD_SHARED);
 
-            // add padding
-            bytes.resize(bytes.len() + program.padding_bytes(), 0);
+        // record each program's pre-payload padding, the absolute position of each inline
+        // payload, and — under `PAYLOAD_SHARED` — the offset of the earlier copy each duplicate
+        // payload references (0 = stored inline)
+        let mut prepads: Vec<usize> = Vec::with_capacity(self.programs.len());
+        let mut payload_positions: Vec<usize> = Vec::with_capacity(self.programs.len());
+        let mut payload_offsets: Vec<u32> = Vec::with_capacity(self.programs.len());
+        let mut total_size = size_of::<VptHeader>();
+        for (i, (program, payload)) in self.programs.iter().zip(payloads.iter()).enumerate() {
+            if payload_aligned && total_size % 16 != ALIGN_16_HEADER_REM {
+                total_size += 8;
+            }
+
+            // empty payloads stay inline, since a zero offset means "no reference"; aligned
+            // payloads stay inline too, as a shared copy's placement follows its storing program
+            let shared_from =
+                if shared_payloads && !payload.is_empty() && program.payload_align <= 1 {
+                    (0..i).find(|&j| payload_offsets[j] == 0 && payloads[j] == *payload)
+                } else {
+                    None
+                };
+
+            if let Some(j) = shared_from {
+                prepads.push(0);
+                payload_positions.push(0);
+                payload_offsets.push(payload_positions[j] as u32);
+                total_size = total_size.saturating_add(self.emitted_size(program, &[], 0));
+            } else {
+                let payload_pos = total_size + size_of::<ProgramHeader>();
+                let prepad = payload_pos.next_multiple_of(program.payload_align.max(1) as usize)
+                    - payload_pos;
+                prepads.push(prepad);
+                payload_positions.push(payload_pos + prepad);
+                payload_offsets.push(0);
+                // saturate rather than wrap on 32-bit targets; the result is still wrong past
+                // `u32::MAX`, but deterministically so — `build_checked` rejects such tables
+                total_size = total_size.saturating_add(self.emitted_size(program, payload, prepad));
+            }
         }
+        total_size = total_size.saturating_add(name_table.len());
 
-        bytes
+        Layout {
+            name_table,
+            name_offsets,
+            prepads,
+            payload_offsets,
+            total_size,
+        }
+    }
+
+    /// Empties the builder's program list while retaining its allocation, vendor ID, flags, and
+    /// compression settings.
+    ///
+    /// Together with [`build_into`], this lets a batch pipeline reuse the builder's and output
+    /// buffer's allocations across thousands of builds.
+    ///
+    /// [`build_into`]: `VptBuilder::build_into`
+    pub fn clear(&mut self) {
+        self.programs.clear();
+    }
+
+    /// Empties the builder like [`clear`], additionally replacing its vendor ID.
+    ///
+    /// [`clear`]: `VptBuilder::clear`
+    pub fn clear_with_vendor(&mut self, vendor_id: u32) {
+        self.programs.clear();
+        self.vendor_id = vendor_id;
+    }
+}
+
+// The table layout `VptBuilder::build_into` emits, computed in one pass by
+// `VptBuilder::layout`: the interned name table, per-program offsets and padding, and the total
+// emitted size recorded as `header.size`.
+struct Layout {
+    name_table: Vec<u8>,
+    name_offsets: Vec<u32>,
+    prepads: Vec<usize>,
+    payload_offsets: Vec<u32>,
+    total_size: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builder_with_programs() -> VptBuilder<'static> {
+        let mut builder = VptBuilder::new(0);
+        builder.add_program(ProgramBuilder {
+            name: Cow::Borrowed(b"first"),
+            payload: Cow::Borrowed(&[0xaa; 13]),
+            kind: ProgramKind::Executable,
+            vendor_id: None,
+            payload_align: 1,
+            metadata: None,
+        });
+        builder.add_program(ProgramBuilder {
+            name: Cow::Borrowed(b"second"),
+            payload: Cow::Borrowed(&[0xbb; 7]),
+            kind: ProgramKind::Data,
+            vendor_id: None,
+            payload_align: 1,
+            metadata: None,
+        });
+        builder
+    }
+
+    #[test]
+    fn empty_builder_builds_header_only_blob() {
+        let bytes = VptBuilder::new(7).build();
+        assert_eq!(bytes.len(), size_of::<VptHeader>());
+
+        // `Vec<u8>` carries no alignment guarantee, so copy into aligned storage to parse
+        let vpt = Vpt::new_aligned(&bytes, 7).unwrap();
+        let vpt = vpt.borrow();
+        assert_eq!(vpt.header().size as usize, size_of::<VptHeader>());
+        assert_eq!(vpt.len(), 0);
+        assert!(vpt.is_empty());
+        assert_eq!(vpt.max_payload_len(), 0);
+        assert_eq!(vpt.program_iter().next(), None);
+    }
+
+    #[test]
+    fn build_is_deterministic() {
+        assert_eq!(
+            builder_with_programs().build(),
+            builder_with_programs().build()
+        );
+    }
+
+    #[test]
+    fn deduped_build_stores_identical_payloads_once() {
+        let mut builder = VptBuilder::new(0);
+        builder.add_program(ProgramBuilder::new(&b"a"[..], &[0xcc; 32][..]));
+        builder.add_program(ProgramBuilder::new(&b"b"[..], &[0xdd; 8][..]));
+        builder.add_program(ProgramBuilder::new(&b"c"[..], &[0xcc; 32][..]));
+
+        let plain = builder.clone().build();
+        let deduped = builder.build_deduped();
+        assert!(deduped.len() < plain.len());
+
+        let vpt = Vpt::new_aligned(&deduped, 0).unwrap();
+        let vpt = vpt.borrow();
+        let programs: alloc::vec::Vec<_> = vpt.program_iter().collect();
+        assert_eq!(programs.len(), 3);
+        assert_eq!(programs[0].payload(), &[0xcc; 32]);
+        assert_eq!(programs[1].payload(), &[0xdd; 8]);
+        assert_eq!(programs[2].payload(), &[0xcc; 32]);
+        assert!(!programs[0].payload_is_shared());
+        assert!(programs[2].payload_is_shared());
+        assert_eq!(programs[2].payload_offset(), programs[0].payload_offset());
+    }
+
+    #[test]
+    fn build_checked_counts_padding_toward_the_total() {
+        let mut builder = VptBuilder::new(0);
+        builder
+            .add_program(ProgramBuilder::new(&b"a"[..], &[0u8; 4][..]).with_payload_align(1 << 31));
+        builder
+            .add_program(ProgramBuilder::new(&b"b"[..], &[0u8; 4][..]).with_payload_align(1 << 31));
+
+        assert!(matches!(
+            builder.build_checked(),
+            Err(BuildError::SizeOverflow { .. })
+        ));
+    }
+
+    #[test]
+    fn payload_align_16_lands_payloads_on_16_byte_boundaries() {
+        let mut builder = VptBuilder::new(0);
+        builder.set_flags(VptFlags::PAYLOAD_ALIGN_16);
+        builder.add_program(ProgramBuilder::new(&b"a"[..], &[0x11; 3][..]));
+        builder.add_program(ProgramBuilder::new(&b"bb"[..], &[0x22; 17][..]));
+        builder.add_program(ProgramBuilder::new(&b"ccc"[..], &[0x33; 40][..]));
+
+        let bytes = builder.build();
+        let vpt = Vpt::new_aligned(&bytes, 0).unwrap();
+        let vpt = vpt.borrow();
+        let mut seen = 0;
+        for program in vpt.program_iter() {
+            assert_eq!(
+                program.payload_offset() % 16,
+                0,
+                "payload of {:?} not 16-byte aligned",
+                program.name()
+            );
+            seen += 1;
+        }
+        assert_eq!(seen, 3);
+    }
+
+    #[test]
+    fn metadata_round_trips() {
+        let mut builder = VptBuilder::new(0);
+        builder.add_program(
+            ProgramBuilder::new(&b"described"[..], &[0xaa; 5][..])
+                .with_metadata(&b"a menu description"[..]),
+        );
+        builder.add_program(ProgramBuilder::new(&b"plain"[..], &[0xbb; 3][..]));
+
+        let bytes = builder.build();
+        let vpt = Vpt::new_aligned(&bytes, 0).unwrap();
+        let vpt = vpt.borrow();
+        assert!(vpt.flags().contains(VptFlags::PROGRAM_METADATA));
+
+        let described = vpt.program_by_name(b"described").unwrap();
+        assert_eq!(described.metadata(), Some(&b"a menu description"[..]));
+        assert_eq!(described.payload(), &[0xaa; 5]);
+        assert_eq!(vpt.program_by_name(b"plain").unwrap().metadata(), None);
+    }
+
+    #[test]
+    fn padding_bytes_are_zero() {
+        let bytes = builder_with_programs().build();
+
+        let mut offset = size_of::<VptHeader>();
+        for program in builder_with_programs().programs {
+            let base_end = offset + program.base_size();
+            let padded_end = offset + program.size();
+            assert!(
+                bytes[base_end..padded_end].iter().all(|&b| b == 0),
+                "nonzero padding after program ending at {base_end}"
+            );
+            offset = padded_end;
+        }
+        assert_eq!(offset, bytes.len());
     }
 }
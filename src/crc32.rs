@@ -0,0 +1,49 @@
+//! Small table-based CRC32 (IEEE 802.3) implementation.
+//!
+//! Used for the `checksum` field of the VPT header. Kept in-crate rather than pulling in a
+//! dependency, since the SDK is `no_std` and the routine is tiny.
+
+const fn make_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb88320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+const TABLE: [u32; 256] = make_table();
+
+/// Initial state for an incremental CRC32 computation.
+pub(crate) const INIT: u32 = 0xffffffff;
+
+/// Folds `bytes` into an incremental CRC32 state.
+pub(crate) const fn update(mut crc: u32, bytes: &[u8]) -> u32 {
+    let mut i = 0;
+    while i < bytes.len() {
+        crc = (crc >> 8) ^ TABLE[((crc ^ bytes[i] as u32) & 0xff) as usize];
+        i += 1;
+    }
+    crc
+}
+
+/// Finalizes an incremental CRC32 state.
+pub(crate) const fn finalize(crc: u32) -> u32 {
+    !crc
+}
+
+/// Computes the CRC32 of `bytes`.
+pub(crate) const fn crc32(bytes: &[u8]) -> u32 {
+    finalize(update(INIT, bytes))
+}
@@ -0,0 +1,115 @@
+//! Name-keyed diffing between two VPTs.
+//!
+//! Over-the-air updates on bandwidth-constrained links want to ship only the programs that
+//! changed between an old and a new table, not the whole blob. [`vpt_diff`] computes which
+//! programs were added, changed, or removed, keyed by name; [`apply_diff`] replays a diff on top
+//! of a base table to reconstruct the new one. This module can be used by enabling the `alloc`
+//! feature; [`apply_diff`] additionally requires the `builder` feature.
+
+use alloc::vec::Vec;
+
+use crate::{Program, Vpt};
+
+/// The difference between two VPTs, obtained from [`vpt_diff`].
+///
+/// Entries are zero-copy views borrowing from the compared blobs, so a diff costs no payload
+/// copies; serialization for transport is left to the caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VptDiff<'a> {
+    /// Programs present in the new VPT but not the old, as views into the new blob.
+    pub added: Vec<Program<'a>>,
+    /// Programs present in both whose payloads differ, as views into the new blob.
+    pub changed: Vec<Program<'a>>,
+    /// Names of programs present in the old VPT but not the new.
+    pub removed: Vec<&'a [u8]>,
+}
+
+impl VptDiff<'_> {
+    /// Returns `true` if the diff records no differences.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
+
+    /// Returns the number of recorded differences.
+    pub fn len(&self) -> usize {
+        self.added.len() + self.changed.len() + self.removed.len()
+    }
+}
+
+/// Computes the name-keyed difference between `old` and `new`.
+///
+/// Programs are matched by name: a name only in `new` is recorded as added, a name only in `old`
+/// as removed, and a name in both with differing payloads as changed. The comparison is O(n²)
+/// but allocation-light, which suits the small tables VPTs are built for. Tables with duplicate
+/// names diff by each name's first occurrence.
+pub fn vpt_diff<'a>(old: &Vpt<'a>, new: &Vpt<'a>) -> VptDiff<'a> {
+    let mut diff = VptDiff {
+        added: Vec::new(),
+        changed: Vec::new(),
+        removed: Vec::new(),
+    };
+
+    for program in new.program_iter() {
+        match old.program_by_name(program.name()) {
+            None => diff.added.push(program),
+            Some(old_program) if old_program.payload() != program.payload() => {
+                diff.changed.push(program);
+            }
+            Some(_) => {}
+        }
+    }
+
+    for program in old.program_iter() {
+        if !new.contains_name(program.name()) {
+            diff.removed.push(program.name());
+        }
+    }
+
+    diff
+}
+
+/// Replays `diff` on top of `base`, returning the reconstructed VPT's bytes.
+///
+/// Programs removed by the diff are dropped, changed programs take the diff's payload, and added
+/// programs are appended after the base's, so `apply_diff(&old, &vpt_diff(&old, &new))` yields a
+/// table with the same programs as `new`. Like [`VptBuilder::from_vpt`], payloads are re-emitted
+/// uncompressed and unknown kinds degrade to [`ProgramKind::Data`].
+///
+/// [`VptBuilder::from_vpt`]: `crate::VptBuilder::from_vpt`
+/// [`ProgramKind::Data`]: `crate::ProgramKind::Data`
+#[cfg(feature = "builder")]
+pub fn apply_diff(base: &Vpt<'_>, diff: &VptDiff<'_>) -> Vec<u8> {
+    use alloc::borrow::Cow;
+
+    use crate::{ProgramBuilder, ProgramKind, VptBuilder};
+
+    let program = |source: Program<'_>| ProgramBuilder {
+        name: Cow::Owned(source.name().to_vec()),
+        payload: Cow::Owned(source.payload().to_vec()),
+        kind: source.kind().unwrap_or(ProgramKind::Data),
+        vendor_id: source.vendor_id(),
+        payload_align: 1,
+        metadata: source.metadata().map(|m| Cow::Owned(m.to_vec())),
+    };
+
+    let mut builder =
+        VptBuilder::with_capacity(base.vendor_id(), base.len() as usize + diff.added.len());
+
+    for base_program in base.program_iter() {
+        if diff.removed.contains(&base_program.name()) {
+            continue;
+        }
+
+        let changed = diff
+            .changed
+            .iter()
+            .find(|p| p.name() == base_program.name());
+        builder.add_program(program(changed.copied().unwrap_or(base_program)));
+    }
+
+    for added in &diff.added {
+        builder.add_program(program(*added));
+    }
+
+    builder.build()
+}
@@ -0,0 +1,274 @@
+//! Offset-indexed VPT views.
+//!
+//! Programs are variable-length with forward offsets, so the plain [`ProgramIter`] can only walk
+//! front to back. [`IndexedVpt`] scans the blob once up front, recording each program's byte
+//! offset in a fixed-size table, and uses it to offer O(1) random access and reverse iteration
+//! without an allocator.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use thiserror::Error;
+
+use crate::{Program, ProgramIter, Vpt};
+
+/// Maximum number of programs an [`IndexedVpt`] can index.
+pub const MAX_INDEXED_PROGRAMS: usize = 64;
+
+/// An error encountered while building an [`IndexedVpt`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum IndexError {
+    /// The VPT contains more programs than [`MAX_INDEXED_PROGRAMS`].
+    #[error("too many programs to index: {0} exceeds cap of {MAX_INDEXED_PROGRAMS}")]
+    TooManyPrograms(u32),
+}
+
+/// A [`Vpt`] with a precomputed table of program offsets, obtained from [`Vpt::indexed`].
+///
+/// The offset table enables O(1) [`program_at`] lookups and reverse iteration via
+/// [`DoubleEndedIterator`], neither of which the sequential [`ProgramIter`] can offer. The table
+/// is held inline in a fixed-size array, so no allocator is required.
+///
+/// [`program_at`]: `IndexedVpt::program_at`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexedVpt<'a> {
+    vpt: Vpt<'a>,
+    // Invariant: the first `count` entries are offsets of valid programs within `vpt`.
+    offsets: [u32; MAX_INDEXED_PROGRAMS],
+    count: u32,
+}
+
+/// Double-ended program iterator obtained from [`IndexedVpt::iter`].
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexedProgramIter<'i, 'a> {
+    indexed: &'i IndexedVpt<'a>,
+    front: u32,
+    back: u32,
+}
+
+impl<'a> Vpt<'a> {
+    /// Parses the program whose header begins at `offset` within the blob.
+    pub(crate) fn program_at_offset(&self, offset: usize) -> Option<Program<'a>> {
+        let mut iter = ProgramIter {
+            program_count: 1,
+            current_program: 0,
+            flags: self.flags(),
+            offset,
+            bytes: self.bytes.get(offset..)?,
+            name_table: self.name_table().unwrap_or(&[]),
+            table: self.bytes,
+        };
+        iter.next()
+    }
+
+    /// Records the byte offset of each program's header into `buf`, in table order, returning the
+    /// number of offsets written.
+    ///
+    /// This is the allocation-free counterpart to [`build_index`] for callers that provide their
+    /// own offset storage. If `buf` is shorter than the program table, only the first
+    /// `buf.len()` offsets are recorded.
+    ///
+    /// [`build_index`]: `Vpt::build_index`
+    pub fn program_offsets_into(&self, buf: &mut [u32]) -> usize {
+        let mut count = 0;
+        let mut iter = self.program_iter();
+        while count < buf.len() {
+            let offset = iter.offset;
+            if iter.next().is_none() {
+                break;
+            }
+            buf[count] = offset as u32;
+            count += 1;
+        }
+        count
+    }
+
+    /// Scans the VPT once and returns a [`VptIndex`] amortizing the scan cost across many
+    /// lookups.
+    #[cfg(feature = "alloc")]
+    pub fn build_index(&self) -> VptIndex<'a> {
+        let mut offsets = Vec::new();
+        let mut by_name = Vec::new();
+
+        let mut iter = self.program_iter();
+        loop {
+            let offset = iter.offset as u32;
+            let Some(program) = iter.next() else {
+                break;
+            };
+            offsets.push(offset);
+            by_name.push((program.name(), offset));
+        }
+
+        by_name.sort_by(|a, b| a.0.cmp(b.0));
+
+        VptIndex {
+            vpt: *self,
+            offsets,
+            by_name,
+        }
+    }
+
+    /// Scans the VPT once and returns an [`IndexedVpt`] offering O(1) program access and reverse
+    /// iteration.
+    ///
+    /// # Errors
+    ///
+    /// - [`IndexError::TooManyPrograms`] if `header.program_count` exceeds
+    ///   [`MAX_INDEXED_PROGRAMS`].
+    pub fn indexed(&self) -> Result<IndexedVpt<'a>, IndexError> {
+        let program_count = u32::from_le(self.header().program_count);
+        if program_count as usize > MAX_INDEXED_PROGRAMS {
+            return Err(IndexError::TooManyPrograms(program_count));
+        }
+
+        let mut offsets = [0u32; MAX_INDEXED_PROGRAMS];
+        let mut count = 0u32;
+
+        let mut iter = self.program_iter();
+        loop {
+            let offset = iter.offset;
+            if iter.next().is_none() {
+                break;
+            }
+            offsets[count as usize] = offset as u32;
+            count += 1;
+        }
+
+        Ok(IndexedVpt {
+            vpt: *self,
+            offsets,
+            count,
+        })
+    }
+}
+
+impl<'a> IndexedVpt<'a> {
+    /// Returns the number of indexed programs.
+    ///
+    /// A truncated blob may contain fewer programs than `header.program_count` claims, in which
+    /// case only the programs that could be parsed are indexed.
+    pub const fn len(&self) -> usize {
+        self.count as usize
+    }
+
+    /// Returns `true` if the VPT contains no programs.
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the [`Vpt`] this index was built from.
+    pub const fn vpt(&self) -> Vpt<'a> {
+        self.vpt
+    }
+
+    /// Returns the program at `index` in O(1), or [`None`] if `index` is out of bounds.
+    pub fn program_at(&self, index: u32) -> Option<Program<'a>> {
+        if index >= self.count {
+            return None;
+        }
+
+        self.vpt
+            .program_at_offset(self.offsets[index as usize] as usize)
+    }
+
+    /// Returns a double-ended iterator over the indexed programs.
+    pub fn iter(&self) -> IndexedProgramIter<'_, 'a> {
+        IndexedProgramIter {
+            indexed: self,
+            front: 0,
+            back: self.count,
+        }
+    }
+}
+
+impl<'i, 'a> IntoIterator for &'i IndexedVpt<'a> {
+    type Item = Program<'a>;
+    type IntoIter = IndexedProgramIter<'i, 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> Iterator for IndexedProgramIter<'_, 'a> {
+    type Item = Program<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        let program = self.indexed.program_at(self.front);
+        self.front += 1;
+        program
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.back - self.front) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for IndexedProgramIter<'_, '_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        self.indexed.program_at(self.back)
+    }
+}
+
+impl ExactSizeIterator for IndexedProgramIter<'_, '_> {}
+
+/// A heap-backed index over a [`Vpt`]'s programs, obtained from [`Vpt::build_index`].
+///
+/// Unlike [`IndexedVpt`], the offset table is stored in [`Vec`]s with no cap on the number of
+/// programs, and names are kept sorted so [`get`] runs in O(log n). The underlying program data
+/// remains zero-copy.
+///
+/// [`get`]: `VptIndex::get`
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VptIndex<'a> {
+    vpt: Vpt<'a>,
+    // program header offsets in table order
+    offsets: Vec<u32>,
+    // (name, offset) pairs sorted by name
+    by_name: Vec<(&'a [u8], u32)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> VptIndex<'a> {
+    /// Returns the number of indexed programs.
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Returns `true` if the VPT contains no programs.
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    /// Returns the [`Vpt`] this index was built from.
+    pub const fn vpt(&self) -> Vpt<'a> {
+        self.vpt
+    }
+
+    /// Returns the program named `name` in O(log n), or [`None`] if no program with that name
+    /// exists.
+    pub fn get(&self, name: &[u8]) -> Option<Program<'a>> {
+        let i = self.by_name.binary_search_by(|(n, _)| n.cmp(&name)).ok()?;
+        self.vpt.program_at_offset(self.by_name[i].1 as usize)
+    }
+
+    /// Returns the program at `index` in table order in O(1), or [`None`] if `index` is out of
+    /// bounds.
+    pub fn get_at(&self, index: usize) -> Option<Program<'a>> {
+        self.vpt
+            .program_at_offset(*self.offsets.get(index)? as usize)
+    }
+}
@@ -14,15 +14,31 @@
 //! reference the original blob's memory, and inherit its lifetime. So, if a blob is stored as
 //! static data or is dynamically linked to the program, its data will be available for the
 //! entirety of the program without.
+//!
+//! Parsing is panic-free by construction: every read-path slice is bounds-checked and defects
+//! surface as [`VptDefect`] errors, which `clippy::indexing_slicing` enforces on the parsing
+//! `impl`s. The sole exception, [`Vpt::header`], relies on the invariant every constructor
+//! establishes and has [`Vpt::header_checked`] as its re-checking counterpart.
 
 #![no_std]
 #![warn(missing_docs)]
 
-#[cfg(feature = "builder")]
+#[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[cfg(feature = "builder")]
 mod builder;
+mod crc32;
+#[cfg(feature = "alloc")]
+mod diff;
+mod indexed;
+mod mutable;
+#[cfg(feature = "alloc")]
+mod owned;
+#[cfg(feature = "std")]
+mod stream;
 
 use core::fmt::Display;
 
@@ -31,19 +47,243 @@ use thiserror::Error;
 
 #[cfg(feature = "builder")]
 pub use crate::builder::{ProgramBuilder, VptBuilder};
+#[cfg(feature = "builder")]
+pub use crate::diff::apply_diff;
+#[cfg(feature = "alloc")]
+pub use crate::diff::{VptDiff, vpt_diff};
+#[cfg(feature = "alloc")]
+pub use crate::indexed::VptIndex;
+pub use crate::indexed::{IndexError, IndexedProgramIter, IndexedVpt, MAX_INDEXED_PROGRAMS};
+pub use crate::mutable::{ProgramMut, VptMut};
+#[cfg(feature = "alloc")]
+pub use crate::owned::{OwnedProgram, OwnedVpt, VptBuf, VptSnapshot};
+#[cfg(feature = "std")]
+pub use crate::stream::VptStreamWriter;
 
 /// Magic number used to identify VPTs.
 pub const VPT_MAGIC: u32 = 0x675c3ed9;
 
 /// VPT version this SDK is built against.
-pub const SDK_VERSION: Version = Version { major: 0, minor: 1 };
+pub const SDK_VERSION: Version = Version {
+    major: 0,
+    minor: 12,
+};
+
+/// Alignment, in bytes, of a VPT blob and of every structure within it.
+pub const VPT_ALIGNMENT: usize = 8;
 
-const fn align8(n: usize) -> usize {
+/// Rounds `n` up to the next multiple of [`VPT_ALIGNMENT`].
+///
+/// This is the exact rounding the crate uses for inter-program padding, exported so external
+/// tools that compute VPT layouts — for patching or relocation — match it without duplicating
+/// the formula.
+pub const fn align8(n: usize) -> usize {
     (n + 7) & !7
 }
 
+// Residue, mod 16, a program header's offset must have under `VptFlags::PAYLOAD_ALIGN_16` for
+// the payload following the header to land on a 16-byte boundary. Deriving it from the header
+// size keeps writers and readers honest when the header grows.
+pub(crate) const ALIGN_16_HEADER_REM: usize = (16 - size_of::<ProgramHeader>() % 16) % 16;
+
+/// Bitfield of VPT properties stored in `header.flags`.
+///
+/// Readers ignore unknown bits, so new flags can be introduced without breaking older parsers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct VptFlags(u32);
+
+impl VptFlags {
+    /// The program table is sorted by name, enabling binary search via
+    /// [`Vpt::program_by_name_sorted`].
+    pub const NAME_SORTED: VptFlags = VptFlags(1 << 0);
+
+    /// Every program's payload begins at a 16-byte boundary relative to the blob base.
+    ///
+    /// A program header is preceded by 8 padding bytes whenever its natural offset would not
+    /// leave `offset + size_of::<ProgramHeader>()` at a multiple of 16 — with the current
+    /// 48-byte header, whenever the header would not itself sit at a multiple of 16 — so the
+    /// payload following the header lands on a 16-byte boundary. Readers must apply the same
+    /// rule when skipping between programs.
+    pub const PAYLOAD_ALIGN_16: VptFlags = VptFlags(1 << 1);
+
+    /// Every program header carries a CRC32 digest of its payload in `payload_digest`, enabling
+    /// targeted verification via [`Program::verify_payload`] without checksumming the whole
+    /// blob.
+    pub const PAYLOAD_DIGEST: VptFlags = VptFlags(1 << 2);
+
+    /// Programs may carry their own vendor ID in `ProgramHeader::vendor_id`, letting one table
+    /// aggregate programs from multiple vendors. A per-program vendor of zero inherits the
+    /// table's `VptHeader::vendor_id`.
+    pub const PROGRAM_VENDORS: VptFlags = VptFlags(1 << 3);
+
+    /// The blob carries a trailing signature block of `header.signature_len` bytes immediately
+    /// after the table's `header.size` bytes. The crate is crypto-agnostic: it locates the block
+    /// — see [`Vpt::signature`] and [`Vpt::signed_bytes`] — and leaves verification to the
+    /// caller's chosen algorithm.
+    pub const SIGNED: VptFlags = VptFlags(1 << 4);
+
+    /// Program names live in a shared name table at the end of the table region instead of
+    /// inline after each payload. `ProgramHeader::name_offset` and `name_len` index into the
+    /// table, which [`Vpt::name_table`] exposes. Compact for tables with many programs, at the
+    /// cost of one indirection per name.
+    pub const NAME_TABLE: VptFlags = VptFlags(1 << 5);
+
+    /// Programs may carry pre-payload padding recorded in `ProgramHeader::payload_prepad`,
+    /// placing each payload at a stricter absolute alignment than the format's 8 bytes — as
+    /// execute-in-place code may require. Readers must skip the recorded padding between header
+    /// and payload.
+    pub const PAYLOAD_PREPAD: VptFlags = VptFlags(1 << 6);
+
+    /// Byte-identical payloads may be stored once and shared: a program whose
+    /// `ProgramHeader::payload_offset` is nonzero carries no payload bytes of its own and reads
+    /// its payload from that absolute offset within the blob, where an earlier program stores it
+    /// inline. Emitted by [`VptBuilder::build_deduped`]; readers resolve the reference
+    /// transparently, so [`Program::payload`] is unaffected.
+    ///
+    /// [`VptBuilder::build_deduped`]: `crate::VptBuilder::build_deduped`
+    /// [`Program::payload`]: `Program::payload`
+    pub const PAYLOAD_SHARED: VptFlags = VptFlags(1 << 7);
+
+    /// Programs may carry a human-readable metadata string — a menu description, say — of
+    /// `ProgramHeader::meta_len` bytes stored after the inline name, exposed via
+    /// [`Program::metadata`]. Keeps descriptive text out of the name, which stays the lookup
+    /// key.
+    pub const PROGRAM_METADATA: VptFlags = VptFlags(1 << 8);
+
+    /// Returns a bitfield with no flags set.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Constructs a bitfield from raw bits. Unknown bits are retained.
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw bits of the bitfield.
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    pub const fn contains(self, other: VptFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Sets all flags in `other`.
+    pub const fn insert(&mut self, other: VptFlags) {
+        self.0 |= other.0;
+    }
+
+    /// Clears all flags in `other`.
+    pub const fn remove(&mut self, other: VptFlags) {
+        self.0 &= !other.0;
+    }
+}
+
+impl core::ops::BitOr for VptFlags {
+    type Output = VptFlags;
+
+    fn bitor(self, rhs: VptFlags) -> VptFlags {
+        VptFlags(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitOrAssign for VptFlags {
+    fn bitor_assign(&mut self, rhs: VptFlags) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Compression codec applied to a program's payload, stored in `ProgramHeader::compression`.
+///
+/// Codecs other than [`Compression::None`] are gated behind individual cargo features, so a
+/// reader built without the matching feature reports the codec as unsupported rather than
+/// misparsing the payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum Compression {
+    /// The payload is stored uncompressed.
+    None = 0,
+    /// The payload is an LZ4 block. Enabled by the `lz4` feature.
+    #[cfg(feature = "lz4")]
+    Lz4 = 1,
+}
+
+impl Compression {
+    /// Returns the codec for a raw `ProgramHeader::compression` value, or [`None`] if the codec
+    /// is unknown or its cargo feature is disabled.
+    pub const fn from_raw(raw: u32) -> Option<Self> {
+        match raw {
+            0 => Some(Self::None),
+            #[cfg(feature = "lz4")]
+            1 => Some(Self::Lz4),
+            _ => None,
+        }
+    }
+
+    /// Returns the raw value stored in `ProgramHeader::compression`.
+    pub const fn as_raw(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Kind of module a program entry carries, stored in `ProgramHeader::kind`.
+///
+/// Unknown values are reserved for future kinds, so a reader built against an older SDK reports
+/// them as unrecognized rather than misclassifying the program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum ProgramKind {
+    /// The payload is executable code.
+    Executable = 0,
+    /// The payload is a pure data asset.
+    Data = 1,
+}
+
+impl ProgramKind {
+    /// Returns the kind for a raw `ProgramHeader::kind` value, or [`None`] if the value is
+    /// reserved for a future kind.
+    pub const fn from_raw(raw: u32) -> Option<Self> {
+        match raw {
+            0 => Some(Self::Executable),
+            1 => Some(Self::Data),
+            _ => None,
+        }
+    }
+
+    /// Returns the raw value stored in `ProgramHeader::kind`.
+    pub const fn as_raw(self) -> u32 {
+        self as u32
+    }
+}
+
+/// An error encountered while decompressing a program's payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum DecompressError {
+    /// The program's codec is unknown, or its cargo feature is disabled.
+    #[error("unsupported compression codec {0}")]
+    UnsupportedCodec(u32),
+    /// The output buffer cannot hold the decompressed payload.
+    #[error("buffer too small: payload decompresses to {required} bytes, buffer holds {available}")]
+    BufferTooSmall {
+        /// Number of bytes the decompressed payload requires.
+        required: usize,
+        /// Number of bytes available in the buffer.
+        available: usize,
+    },
+    /// The compressed payload is malformed.
+    #[error("malformed compressed payload")]
+    Corrupt,
+}
+
 /// A version of the VPT spec.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Version {
     /// Major version number.
@@ -53,7 +293,11 @@ pub struct Version {
 }
 
 /// An error encountered while validating a VPT.
+///
+/// This enum is `#[non_exhaustive]`: new defects may be added as validation grows, so matches
+/// should include a wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum VptDefect {
     /// The blob is longer than the provided bytes.
     #[error("VPT blob longer than provided bytes")]
@@ -69,13 +313,120 @@ pub enum VptDefect {
         "incompatible version: SDK version {SDK_VERSION} is not compatible with VPT version {0}"
     )]
     VersionMismatch(Version),
+    /// `header.version` predates the minimum the caller requires; see [`Vpt::new_requiring`].
+    #[error("version too old: VPT version {found} does not satisfy required minimum {required}")]
+    VersionTooOld {
+        /// Version recorded in the header.
+        found: Version,
+        /// Minimum version the caller required.
+        required: Version,
+    },
     /// `header.vendor_id` does not match the provided vendor ID.
-    #[error("vendor ID mismatch: found 0x{0:08x}")]
-    VendorMismatch(u32),
+    #[error("vendor ID mismatch: expected 0x{expected:08x}, found 0x{found:08x}")]
+    VendorMismatch {
+        /// Vendor ID the caller asked for.
+        expected: u32,
+        /// Vendor ID recorded in the header.
+        found: u32,
+    },
+    /// `header.checksum` does not match the CRC32 of the payload.
+    #[error("checksum mismatch: expected 0x{expected:08x}, computed 0x{computed:08x}")]
+    ChecksumMismatch {
+        /// Checksum claimed by the header.
+        expected: u32,
+        /// Checksum computed over the payload.
+        computed: u32,
+    },
+    /// A program claims more bytes than are available in the blob.
+    #[error("program {index} at offset {offset} out of bounds")]
+    ProgramOutOfBounds {
+        /// Index of the out-of-bounds program.
+        index: u32,
+        /// Byte offset of the program's header within the blob.
+        offset: usize,
+    },
+    /// A program's payload claims more bytes than are available in the blob.
+    #[error("payload of program {index} out of bounds")]
+    PayloadOutOfBounds {
+        /// Index of the program with the out-of-bounds payload.
+        index: u32,
+    },
+    /// A program's name claims more bytes than are available in the blob.
+    #[error("name of program {index} out of bounds")]
+    NameOutOfBounds {
+        /// Index of the program with the out-of-bounds name.
+        index: u32,
+    },
+    /// The blob contains fewer programs than `header.program_count` claims.
+    #[error("program count mismatch: header claims {expected} programs, found {found}")]
+    ProgramCountMismatch {
+        /// Number of programs claimed by the header.
+        expected: u32,
+        /// Number of well-formed programs found in the blob.
+        found: u32,
+    },
+    /// The VPT's declared size exceeds the caller's limit; see [`Vpt::new_bounded`].
+    #[error("VPT of {size} bytes exceeds limit of {limit} bytes")]
+    TooLarge {
+        /// Size the blob's header declares.
+        size: u32,
+        /// Limit the caller imposed.
+        limit: usize,
+    },
+    /// The provided bytes extend past the VPT's declared size; see [`vpt_size_check`].
+    #[error("{extra} trailing bytes past the VPT's declared size")]
+    TrailingBytes {
+        /// Number of bytes past the end of the declared blob.
+        extra: usize,
+    },
+}
+
+/// An error encountered while building a VPT.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum BuildError {
+    /// Two programs share the same name.
+    #[cfg(feature = "alloc")]
+    #[error("duplicate program name: {name:?}")]
+    DuplicateName {
+        /// The colliding name.
+        name: alloc::vec::Vec<u8>,
+    },
+    /// The provided buffer is too small to contain the VPT.
+    #[error("buffer too small: VPT requires {required} bytes, buffer holds {available}")]
+    BufferTooSmall {
+        /// Number of bytes the VPT requires.
+        required: usize,
+        /// Number of bytes available in the buffer.
+        available: usize,
+    },
+    /// A size field of the VPT would not fit in a `u32`.
+    #[error("size of {size} bytes overflows the VPT's u32 size fields")]
+    SizeOverflow {
+        /// Size that would have been truncated.
+        size: usize,
+    },
+    /// The number of programs would not fit in `header.program_count`.
+    #[error("{count} programs overflow the VPT's u32 program count")]
+    TooManyPrograms {
+        /// Number of programs in the builder.
+        count: usize,
+    },
+    /// A program's name is empty.
+    #[error("program {index} has an empty name")]
+    EmptyName {
+        /// Index of the program within the builder.
+        index: usize,
+    },
 }
 
 /// VPT Header
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Every integer field is stored little-endian on the wire. Little-endian targets — the V5 and
+/// most hosts — can read fields directly; big-endian hosts must convert via [`from_wire`].
+///
+/// [`from_wire`]: `VptHeader::from_wire`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C, align(8))]
 pub struct VptHeader {
     /// Magic number. Must be equal to [`VPT_MAGIC`], or 0x675c3ed9.
@@ -88,12 +439,171 @@ pub struct VptHeader {
     pub size: u32,
     /// Number of programs contained within the VPT.
     pub program_count: u32,
+    /// CRC32 of all bytes following the header.
+    pub checksum: u32,
+    /// Bitfield of VPT properties; see [`VptFlags`]. Unknown bits are ignored.
+    pub flags: u32,
+    /// Length of the trailing signature block in bytes, present when [`VptFlags::SIGNED`] is
+    /// set. Must be zero otherwise.
+    pub signature_len: u32,
+    /// Length of the name table occupying the last bytes of the table region, present when
+    /// [`VptFlags::NAME_TABLE`] is set. Must be zero otherwise.
+    pub name_table_len: u32,
+}
+
+impl VptHeader {
+    /// Constructs a header with the given vendor ID, blob size, and program count, filling in
+    /// [`VPT_MAGIC`] and [`SDK_VERSION`] automatically.
+    ///
+    /// `checksum`, `flags`, and the signature fields are zero; set them afterward if needed.
+    /// Being `const`, this standardizes correct header construction for VPTs embedded as static
+    /// data in firmware images.
+    pub const fn new(vendor_id: u32, size: u32, program_count: u32) -> Self {
+        Self {
+            magic: VPT_MAGIC,
+            version: SDK_VERSION,
+            vendor_id,
+            size,
+            program_count,
+            checksum: 0,
+            flags: 0,
+            signature_len: 0,
+            name_table_len: 0,
+        }
+    }
+
+    /// Returns the header's wire bytes.
+    ///
+    /// Equivalent to `bytemuck::bytes_of`, but usable in const context, so a `static` VPT blob
+    /// can begin with a header serialized at compile time.
+    pub const fn to_bytes(self) -> [u8; size_of::<VptHeader>()] {
+        // every field is a u32 in declaration order with no padding, which the layout
+        // assertions below pin down; the wire order is little-endian
+        let fields = [
+            self.magic,
+            self.version.major,
+            self.version.minor,
+            self.vendor_id,
+            self.size,
+            self.program_count,
+            self.checksum,
+            self.flags,
+            self.signature_len,
+            self.name_table_len,
+        ];
+
+        let mut bytes = [0u8; size_of::<VptHeader>()];
+        let mut i = 0;
+        while i < fields.len() {
+            let field = fields[i].to_le_bytes();
+            let mut j = 0;
+            while j < field.len() {
+                bytes[i * 4 + j] = field[j];
+                j += 1;
+            }
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Converts the header's fields from wire (little-endian) order into native order.
+    ///
+    /// The format stores every integer little-endian. On little-endian targets — the V5 itself
+    /// and most hosts — this is a no-op that compiles away; big-endian hosts must convert before
+    /// interpreting raw fields. The crate's own accessors convert internally.
+    pub const fn from_wire(self) -> Self {
+        Self {
+            magic: u32::from_le(self.magic),
+            version: Version {
+                major: u32::from_le(self.version.major),
+                minor: u32::from_le(self.version.minor),
+            },
+            vendor_id: u32::from_le(self.vendor_id),
+            size: u32::from_le(self.size),
+            program_count: u32::from_le(self.program_count),
+            checksum: u32::from_le(self.checksum),
+            flags: u32::from_le(self.flags),
+            signature_len: u32::from_le(self.signature_len),
+            name_table_len: u32::from_le(self.name_table_len),
+        }
+    }
+
+    /// Converts the header's fields from native order into wire (little-endian) order, the
+    /// inverse of [`from_wire`].
+    ///
+    /// [`from_wire`]: `VptHeader::from_wire`
+    pub const fn to_wire(self) -> Self {
+        Self {
+            magic: self.magic.to_le(),
+            version: Version {
+                major: self.version.major.to_le(),
+                minor: self.version.minor.to_le(),
+            },
+            vendor_id: self.vendor_id.to_le(),
+            size: self.size.to_le(),
+            program_count: self.program_count.to_le(),
+            checksum: self.checksum.to_le(),
+            flags: self.flags.to_le(),
+            signature_len: self.signature_len.to_le(),
+            name_table_len: self.name_table_len.to_le(),
+        }
+    }
 }
 
 unsafe impl Zeroable for VptHeader {}
 unsafe impl AnyBitPattern for VptHeader {}
 unsafe impl NoUninit for VptHeader {}
 
+// The format is persisted and shared across implementations, so the wire layout of both headers
+// is pinned down here: a field reorder or size change becomes a compile failure instead of a
+// silent incompatibility with existing blobs. The lack-of-padding these sizes imply is also what
+// makes the `NoUninit` impls above sound.
+const _: () = {
+    use core::mem::offset_of;
+
+    assert!(size_of::<VptHeader>() == 40);
+    assert!(align_of::<VptHeader>() == VPT_ALIGNMENT);
+    assert!(offset_of!(VptHeader, magic) == 0);
+    assert!(offset_of!(VptHeader, version) == 4);
+    assert!(offset_of!(VptHeader, vendor_id) == 12);
+    assert!(offset_of!(VptHeader, size) == 16);
+    assert!(offset_of!(VptHeader, program_count) == 20);
+    assert!(offset_of!(VptHeader, checksum) == 24);
+    assert!(offset_of!(VptHeader, flags) == 28);
+    assert!(offset_of!(VptHeader, signature_len) == 32);
+    assert!(offset_of!(VptHeader, name_table_len) == 36);
+
+    assert!(size_of::<ProgramHeader>() == 48);
+    assert!(align_of::<ProgramHeader>() == VPT_ALIGNMENT);
+    assert!(offset_of!(ProgramHeader, name_len) == 0);
+    assert!(offset_of!(ProgramHeader, payload_len) == 4);
+    assert!(offset_of!(ProgramHeader, compression) == 8);
+    assert!(offset_of!(ProgramHeader, uncompressed_len) == 12);
+    assert!(offset_of!(ProgramHeader, kind) == 16);
+    assert!(offset_of!(ProgramHeader, payload_digest) == 20);
+    assert!(offset_of!(ProgramHeader, vendor_id) == 24);
+    assert!(offset_of!(ProgramHeader, name_offset) == 28);
+    assert!(offset_of!(ProgramHeader, payload_prepad) == 32);
+    assert!(offset_of!(ProgramHeader, payload_offset) == 36);
+    assert!(offset_of!(ProgramHeader, meta_len) == 40);
+    assert!(offset_of!(ProgramHeader, reserved) == 44);
+
+    assert!(size_of::<Version>() == 8);
+};
+
+// The read-side views are shared-byte wrappers and so `Send + Sync` by auto-trait derivation,
+// which concurrent host tooling relies on. Pinning that down here turns a field addition that
+// would lose the auto traits — an `Rc`, a `Cell` — into a compile failure instead of a silent
+// semver break.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Vpt<'static>>();
+    assert_send_sync::<Program<'static>>();
+    assert_send_sync::<ProgramIter<'static>>();
+    assert_send_sync::<Version>();
+};
+
 /// A read-only view of a validated VPT.
 ///
 /// This VPT has been verified to be version-compatible with SDK, well-aligned, and contain a
@@ -102,33 +612,192 @@ unsafe impl NoUninit for VptHeader {}
 /// through `header.program_count` programs, or it has exhausted the total number of bytes in the
 /// blob.
 ///
+/// The derived [`PartialEq`] is a strict byte comparison of the underlying blobs; for equality
+/// that ignores non-semantic byte differences such as padding, see [`logical_eq`] and
+/// [`semantic_eq`].
+///
+/// `Vpt` and the views derived from it — [`Program`], [`ProgramIter`] — wrap shared byte slices
+/// and are `Send` and `Sync`: a parsed table can be shared across threads and iterated from each
+/// concurrently. This is asserted at compile time, so it holds by contract rather than by
+/// accident of the current field set.
+///
 /// [`program_iter`]: `Vpt::program_iter`
+/// [`logical_eq`]: `Vpt::logical_eq`
+/// [`semantic_eq`]: `Vpt::semantic_eq`
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Vpt<'a> {
-    // Invariant: `bytes` contains a well-aligned VPT with a valid header.
+    // Invariant: `bytes` contains a well-aligned VPT with a valid header, and is at least
+    // `size_of::<VptHeader>()` bytes long.
     bytes: &'a [u8],
+    // Trailing signature block following `bytes` in the original blob; empty when the VPT is
+    // unsigned or the signature was not available at construction.
+    signature: &'a [u8],
 }
 
 /// Program Header
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C, align(8))]
 pub struct ProgramHeader {
     /// Length of the program's name in bytes.
     pub name_len: u32,
-    /// Length of the program's payload in bytes.
+    /// Length of the program's payload in bytes, as stored in the blob.
     pub payload_len: u32,
+    /// Compression codec applied to the payload; see [`Compression`].
+    pub compression: u32,
+    /// Length of the payload in bytes once decompressed. Equal to `payload_len` when the payload
+    /// is uncompressed.
+    pub uncompressed_len: u32,
+    /// Kind of module the payload carries; see [`ProgramKind`].
+    pub kind: u32,
+    /// CRC32 digest of the payload as stored in the blob, present when
+    /// [`VptFlags::PAYLOAD_DIGEST`] is set in the VPT's header. Must be zero otherwise.
+    pub payload_digest: u32,
+    /// Vendor that owns this program, meaningful when [`VptFlags::PROGRAM_VENDORS`] is set in
+    /// the VPT's header. Zero means the program inherits `VptHeader::vendor_id`.
+    pub vendor_id: u32,
+    /// Offset of the program's name within the name table, meaningful when
+    /// [`VptFlags::NAME_TABLE`] is set in the VPT's header. Must be zero otherwise.
+    pub name_offset: u32,
+    /// Number of padding bytes between this header and the payload, meaningful when
+    /// [`VptFlags::PAYLOAD_PREPAD`] is set in the VPT's header. Must be zero otherwise.
+    pub payload_prepad: u32,
+    /// Absolute byte offset within the blob of this program's payload when it is shared with an
+    /// earlier program, meaningful when [`VptFlags::PAYLOAD_SHARED`] is set in the VPT's header.
+    /// Zero means the payload is stored inline after this header. Must be zero otherwise.
+    pub payload_offset: u32,
+    /// Length of the program's metadata string in bytes, stored after the inline name,
+    /// meaningful when [`VptFlags::PROGRAM_METADATA`] is set in the VPT's header. Must be zero
+    /// otherwise.
+    pub meta_len: u32,
+    /// Reserved for future use. Must be zero. Keeps the header free of padding, which the
+    /// `bytemuck` impls require.
+    pub reserved: u32,
 }
 
 unsafe impl Zeroable for ProgramHeader {}
 unsafe impl AnyBitPattern for ProgramHeader {}
 unsafe impl NoUninit for ProgramHeader {}
 
+impl ProgramHeader {
+    /// Checks that the program this header describes fits within `remaining_bytes`, the number
+    /// of bytes from the start of the header to the end of the blob.
+    ///
+    /// All arithmetic is checked, so length fields that would wrap `usize` on 32-bit targets are
+    /// rejected rather than slicing at a wrapped offset. [`ProgramIter::try_next`] routes its
+    /// bounds checks through this method; it is public so manual parsers of the format can reuse
+    /// the same validation.
+    ///
+    /// Defects are reported with an `index` (and `offset`) of 0, since a lone header carries no
+    /// table position; the iterator rewrites them with the program's actual position.
+    ///
+    /// The check assumes the payload is stored inline. Under [`VptFlags::PAYLOAD_SHARED`], a
+    /// header with a nonzero `payload_offset` carries no payload bytes of its own, and the
+    /// iterator bounds-checks the shared reference separately instead of calling this.
+    ///
+    /// # Errors
+    ///
+    /// - [`VptDefect::ProgramOutOfBounds`] if the length fields overflow `usize`.
+    /// - [`VptDefect::PayloadOutOfBounds`] if the payload overruns `remaining_bytes`.
+    /// - [`VptDefect::NameOutOfBounds`] if the name overruns `remaining_bytes`.
+    pub const fn validate(&self, remaining_bytes: usize) -> Result<(), VptDefect> {
+        let overflow = VptDefect::ProgramOutOfBounds {
+            index: 0,
+            offset: 0,
+        };
+
+        // `payload_prepad` must be zero unless `VptFlags::PAYLOAD_PREPAD` is set, so it can be
+        // accounted for unconditionally
+        let header_and_prepad = match size_of::<ProgramHeader>()
+            .checked_add(u32::from_le(self.payload_prepad) as usize)
+        {
+            Some(n) => n,
+            None => return Err(overflow),
+        };
+        let header_and_payload =
+            match header_and_prepad.checked_add(u32::from_le(self.payload_len) as usize) {
+                Some(n) => n,
+                None => return Err(overflow),
+            };
+        if header_and_payload > remaining_bytes {
+            return Err(VptDefect::PayloadOutOfBounds { index: 0 });
+        }
+
+        let total = match header_and_payload.checked_add(u32::from_le(self.name_len) as usize) {
+            Some(n) => n,
+            None => return Err(overflow),
+        };
+        if total > remaining_bytes {
+            return Err(VptDefect::NameOutOfBounds { index: 0 });
+        }
+
+        // `meta_len` must likewise be zero unless `VptFlags::PROGRAM_METADATA` is set; metadata
+        // trails the inline name
+        let total = match total.checked_add(u32::from_le(self.meta_len) as usize) {
+            Some(n) => n,
+            None => return Err(overflow),
+        };
+        if total > remaining_bytes {
+            return Err(overflow);
+        }
+
+        Ok(())
+    }
+
+    /// Converts the header's fields from wire (little-endian) order into native order, like
+    /// [`VptHeader::from_wire`].
+    pub const fn from_wire(self) -> Self {
+        Self {
+            name_len: u32::from_le(self.name_len),
+            payload_len: u32::from_le(self.payload_len),
+            compression: u32::from_le(self.compression),
+            uncompressed_len: u32::from_le(self.uncompressed_len),
+            kind: u32::from_le(self.kind),
+            payload_digest: u32::from_le(self.payload_digest),
+            vendor_id: u32::from_le(self.vendor_id),
+            name_offset: u32::from_le(self.name_offset),
+            payload_prepad: u32::from_le(self.payload_prepad),
+            payload_offset: u32::from_le(self.payload_offset),
+            meta_len: u32::from_le(self.meta_len),
+            reserved: u32::from_le(self.reserved),
+        }
+    }
+
+    /// Converts the header's fields from native order into wire (little-endian) order, the
+    /// inverse of [`from_wire`].
+    ///
+    /// [`from_wire`]: `ProgramHeader::from_wire`
+    pub const fn to_wire(self) -> Self {
+        Self {
+            name_len: self.name_len.to_le(),
+            payload_len: self.payload_len.to_le(),
+            compression: self.compression.to_le(),
+            uncompressed_len: self.uncompressed_len.to_le(),
+            kind: self.kind.to_le(),
+            payload_digest: self.payload_digest.to_le(),
+            vendor_id: self.vendor_id.to_le(),
+            name_offset: self.name_offset.to_le(),
+            payload_prepad: self.payload_prepad.to_le(),
+            payload_offset: self.payload_offset.to_le(),
+            meta_len: self.meta_len.to_le(),
+            reserved: self.reserved.to_le(),
+        }
+    }
+}
+
 /// A read-only view of a program's name and payload. This view has the same lifetime as the [`Vpt`]
 /// it originated from.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Program<'a> {
+    header: &'a ProgramHeader,
+    // flags of the containing VPT, which govern how header fields are interpreted
+    flags: VptFlags,
     name: &'a [u8],
     payload: &'a [u8],
+    // metadata string trailing the inline name, empty unless `VptFlags::PROGRAM_METADATA` is set
+    metadata: &'a [u8],
+    // byte offset of the payload within the blob, for alignment-sensitive callers
+    payload_offset: usize,
 }
 
 /// VPT program iterator obtained from [`Vpt::program_iter`]. This iterator will continue to
@@ -140,7 +809,16 @@ pub struct ProgramIter<'a> {
     // copy directly from VPT and don't modify
     program_count: u32,
     current_program: u32,
+    flags: VptFlags,
+    // byte offset of the next program's header within the original blob
+    offset: usize,
     bytes: &'a [u8],
+    // shared name table, empty unless `VptFlags::NAME_TABLE` is set
+    name_table: &'a [u8],
+    // the full table region, used to resolve payload references under
+    // `VptFlags::PAYLOAD_SHARED`; `bytes` shrinks as iteration advances, but a shared payload
+    // may sit behind the cursor
+    table: &'a [u8],
 }
 
 impl Version {
@@ -153,14 +831,45 @@ impl Version {
                 true
             }
     }
+
+    /// Returns `true` if this version is greater than or equal to `other`.
+    pub const fn is_at_least(&self, other: &Version) -> bool {
+        self.major > other.major || (self.major == other.major && self.minor >= other.minor)
+    }
+
+    /// Returns `true` if this version satisfies `required` as a minimum: same major version, and
+    /// a minor version at least as new.
+    ///
+    /// [`compatible_with`] answers whether a blob can be read at all; this answers whether it is
+    /// new enough for a reader that depends on a field added in a later minor — see
+    /// [`Vpt::new_requiring`]. Unlike [`is_at_least`], a newer major does not satisfy an older
+    /// one, since fields do not carry across majors.
+    ///
+    /// [`compatible_with`]: `Version::compatible_with`
+    /// [`is_at_least`]: `Version::is_at_least`
+    pub const fn satisfies(&self, required: &Version) -> bool {
+        self.major == required.major && self.minor >= required.minor
+    }
+
+    /// Returns `true` if this version falls within the inclusive `[min, max]` window.
+    ///
+    /// Unlike [`compatible_with`], which encodes the VPT spec's compatibility rule, this checks
+    /// an arbitrary band of versions a loader supports.
+    ///
+    /// [`compatible_with`]: `Version::compatible_with`
+    pub const fn in_range(&self, min: &Version, max: &Version) -> bool {
+        self.is_at_least(min) && max.is_at_least(self)
+    }
 }
 
 impl Display for Version {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "v{}.{}", self.major, self.minor)
+        write!(f, "{}.{}", self.major, self.minor)
     }
 }
 
+// the parsing paths must never panic on a hostile blob; see the crate docs
+#[deny(clippy::indexing_slicing)]
 impl<'a> Vpt<'a> {
     /// Constructs a [`Vpt`] from a byte slice.
     ///
@@ -172,15 +881,154 @@ impl<'a> Vpt<'a> {
     /// - [`VptDefect::VersionMismatch`] if `header.version` is not compatible with [`SDK_VERSION`].
     /// - [`VptDefect::VendorMismatch`] if `header.vendor_id` does not match `vendor_id`.
     pub fn new(bytes: &'a [u8], vendor_id: u32) -> Result<Self, VptDefect> {
-        if bytes.len() < size_of::<VptHeader>() {
-            return Err(VptDefect::SizeMismatch);
+        Self::new_inner(bytes, Some(vendor_id))
+    }
+
+    /// Constructs a [`Vpt`] from a byte slice without checking its vendor ID.
+    ///
+    /// Inspection tools and registries that handle VPTs from arbitrary vendors can parse with
+    /// this and read [`vendor_id`] afterward to decide what to do; loaders that must enforce a
+    /// specific vendor should keep using [`new`].
+    ///
+    /// # Errors
+    ///
+    /// All errors returned by [`new`] except [`VptDefect::VendorMismatch`].
+    ///
+    /// [`new`]: `Vpt::new`
+    /// [`vendor_id`]: `Vpt::vendor_id`
+    pub fn new_any_vendor(bytes: &'a [u8]) -> Result<Self, VptDefect> {
+        Self::new_inner(bytes, None)
+    }
+
+    /// Constructs a [`Vpt`] from a table embedded at `offset` within a larger container.
+    ///
+    /// Firmware images often concatenate a VPT after other sections — a bootloader header, say —
+    /// so the table does not begin at offset 0. This slices `bytes` at `offset` and validates
+    /// the result like [`new`], formalizing the bounds and alignment handling the caller would
+    /// otherwise hand-roll. The returned [`Vpt`] borrows `offset..offset + header.size` of the
+    /// container.
+    ///
+    /// # Errors
+    ///
+    /// All errors returned by [`new`], plus:
+    ///
+    /// - [`VptDefect::SizeMismatch`] if `offset` is beyond the end of `bytes`.
+    /// - [`VptDefect::AlignmentMismatch`] if the table at `offset` is not 8-byte aligned —
+    ///   either the offset is not a multiple of 8 within an aligned container, or the container
+    ///   itself is misaligned.
+    ///
+    /// [`new`]: `Vpt::new`
+    pub fn new_at(bytes: &'a [u8], offset: usize, vendor_id: u32) -> Result<Self, VptDefect> {
+        let bytes = bytes.get(offset..).ok_or(VptDefect::SizeMismatch)?;
+        Self::new(bytes, vendor_id)
+    }
+
+    /// Scans `bytes` for the first valid VPT, returning its byte offset and the parsed table.
+    ///
+    /// A recovery aid for flash dumps and concatenated images where the table's position is
+    /// unknown: every 8-byte-aligned offset is checked for [`VPT_MAGIC`] and full validation is
+    /// attempted on each candidate. Only aligned offsets are considered — the format requires
+    /// 8-byte alignment — which keeps the scan cheap and skips coincidental magic bytes at
+    /// misaligned positions.
+    ///
+    /// Candidates whose validation fails are skipped rather than reported, so a corrupt leading
+    /// copy does not hide an intact one later in the dump.
+    pub fn find_in(bytes: &'a [u8], vendor_id: u32) -> Option<(usize, Vpt<'a>)> {
+        let magic = VPT_MAGIC.to_le_bytes();
+        let mut offset = bytes.as_ptr().align_offset(VPT_ALIGNMENT);
+
+        while offset + size_of::<VptHeader>() <= bytes.len() {
+            if bytes.get(offset..offset + size_of::<u32>()) == Some(magic.as_slice())
+                && let Some(candidate) = bytes.get(offset..)
+                && let Ok(vpt) = Self::new(candidate, vendor_id)
+            {
+                return Some((offset, vpt));
+            }
+            offset += VPT_ALIGNMENT;
+        }
+
+        None
+    }
+
+    /// Constructs a [`Vpt`] from a byte slice, rejecting tables larger than `max_size` bytes.
+    ///
+    /// A blob arriving over an untrusted channel can declare any `header.size` up to
+    /// [`u32::MAX`]; capping it up front bounds the work every later operation — iteration,
+    /// checksumming, copying — can be made to perform. The limit is checked against the declared
+    /// size, so an oversized claim is reported as [`VptDefect::TooLarge`] even when the slice
+    /// itself is small.
+    ///
+    /// # Errors
+    ///
+    /// All errors returned by [`new`], plus:
+    ///
+    /// - [`VptDefect::TooLarge`] if `header.size` exceeds `max_size`.
+    ///
+    /// [`new`]: `Vpt::new`
+    pub fn new_bounded(
+        bytes: &'a [u8],
+        vendor_id: u32,
+        max_size: usize,
+    ) -> Result<Self, VptDefect> {
+        // peek at the declared size before the full validation walk, so a hostile header is
+        // rejected on its claim alone
+        if let Some(header_bytes) = bytes.get(..size_of::<VptHeader>()) {
+            let header = bytemuck::try_from_bytes::<VptHeader>(header_bytes)
+                .map_err(|_| VptDefect::AlignmentMismatch)?
+                .from_wire();
+            if header.size as usize > max_size {
+                return Err(VptDefect::TooLarge {
+                    size: header.size,
+                    limit: max_size,
+                });
+            }
+        }
+
+        Self::new(bytes, vendor_id)
+    }
+
+    /// Constructs a [`Vpt`] from a byte slice, additionally rejecting blobs older than
+    /// `min_version`.
+    ///
+    /// [`new`] accepts any version compatible with [`SDK_VERSION`], but a loader that depends on
+    /// a field added in a later minor — a flag, a checksum — would read a zeroed stand-in from
+    /// an older blob. Requiring the minor that introduced the field refuses such blobs outright;
+    /// see [`Version::satisfies`].
+    ///
+    /// # Errors
+    ///
+    /// All errors returned by [`new`], plus:
+    ///
+    /// - [`VptDefect::VersionTooOld`] if `header.version` does not satisfy `min_version`.
+    ///
+    /// [`new`]: `Vpt::new`
+    pub fn new_requiring(
+        bytes: &'a [u8],
+        vendor_id: u32,
+        min_version: Version,
+    ) -> Result<Self, VptDefect> {
+        let vpt = Self::new(bytes, vendor_id)?;
+        let found = vpt.version();
+        if !found.satisfies(&min_version) {
+            return Err(VptDefect::VersionTooOld {
+                found,
+                required: min_version,
+            });
         }
+        Ok(vpt)
+    }
 
-        let header: &VptHeader = bytemuck::try_from_bytes(&bytes[..size_of::<VptHeader>()])
+    fn new_inner(bytes: &'a [u8], vendor_id: Option<u32>) -> Result<Self, VptDefect> {
+        let header_bytes = bytes
+            .get(..size_of::<VptHeader>())
+            .ok_or(VptDefect::SizeMismatch)?;
+
+        let header = bytemuck::try_from_bytes::<VptHeader>(header_bytes)
             .map_err(|err| match err {
                 PodCastError::AlignmentMismatch => VptDefect::AlignmentMismatch,
                 _ => unreachable!(),
-            })?;
+            })?
+            .from_wire();
 
         if header.magic != VPT_MAGIC {
             return Err(VptDefect::MagicMismatch(header.magic));
@@ -190,25 +1038,102 @@ impl<'a> Vpt<'a> {
             return Err(VptDefect::VersionMismatch(header.version));
         }
 
-        if header.vendor_id != vendor_id {
-            return Err(VptDefect::VendorMismatch(header.vendor_id));
+        if let Some(vendor_id) = vendor_id
+            && header.vendor_id != vendor_id
+        {
+            return Err(VptDefect::VendorMismatch {
+                expected: vendor_id,
+                found: header.vendor_id,
+            });
+        }
+
+        // `size` must cover at least the header itself, or the trimmed slice below would be too
+        // short for `header()` to slice into.
+        if (header.size as usize) < size_of::<VptHeader>() {
+            return Err(VptDefect::SizeMismatch);
         }
 
-        if bytes.len() < header.size as usize {
+        let table = bytes
+            .get(..header.size as usize)
+            .ok_or(VptDefect::SizeMismatch)?;
+
+        // the name table occupies the last `name_table_len` bytes of the table region; it must
+        // not reach into the header, or `name_table()` would slice past the program region
+        if VptFlags(header.flags).contains(VptFlags::NAME_TABLE)
+            && header.name_table_len as usize > header.size as usize - size_of::<VptHeader>()
+        {
             return Err(VptDefect::SizeMismatch);
         }
 
+        let signature = if VptFlags(header.flags).contains(VptFlags::SIGNED) {
+            let start = header.size as usize;
+            let end = start
+                .checked_add(header.signature_len as usize)
+                .ok_or(VptDefect::SizeMismatch)?;
+            bytes.get(start..end).ok_or(VptDefect::SizeMismatch)?
+        } else {
+            &[]
+        };
+
         // All invariants have been checked.
 
         Ok(Self {
-            bytes: &bytes[..header.size as usize],
+            bytes: table,
+            signature,
         })
     }
 
+    /// Constructs a [`Vpt`] from a byte slice, verifying the header checksum.
+    ///
+    /// # Errors
+    ///
+    /// All errors returned by [`new`], plus:
+    ///
+    /// - [`VptDefect::ChecksumMismatch`] if `header.checksum` does not match the CRC32 of the
+    ///   bytes following the header.
+    ///
+    /// [`new`]: `Vpt::new`
+    pub fn new_checked(bytes: &'a [u8], vendor_id: u32) -> Result<Self, VptDefect> {
+        let vpt = Self::new(bytes, vendor_id)?;
+
+        let expected = u32::from_le(vpt.header().checksum);
+        let computed = crc32::crc32(vpt.bytes.get(size_of::<VptHeader>()..).unwrap_or(&[]));
+        if expected != computed {
+            return Err(VptDefect::ChecksumMismatch { expected, computed });
+        }
+
+        Ok(vpt)
+    }
+
+    /// Constructs a [`Vpt`] from a byte slice, eagerly validating the bounds of every program.
+    ///
+    /// Unlike [`new`], which defers program bounds checks to iteration, this constructor walks
+    /// every program up front and verifies that its header, payload, name, and padding all fit
+    /// within the blob. Iterating a VPT constructed this way will never terminate early due to
+    /// corruption.
+    ///
+    /// # Errors
+    ///
+    /// All errors returned by [`new`], plus:
+    ///
+    /// - [`VptDefect::ProgramOutOfBounds`] if a program claims more bytes than exist in the blob.
+    ///
+    /// [`new`]: `Vpt::new`
+    pub fn new_validated(bytes: &'a [u8], vendor_id: u32) -> Result<Self, VptDefect> {
+        let vpt = Self::new(bytes, vendor_id)?;
+
+        let mut iter = vpt.program_iter();
+        while iter.try_next()?.is_some() {}
+
+        Ok(vpt)
+    }
+
     /// Constructs a [`Vpt`] from a pointer.
     ///
     /// # Errors
     ///
+    /// - [`VptDefect::SizeMismatch`] if `header.size` is smaller than the header itself, or the
+    ///   table plus its signature block would overflow the address space.
     /// - [`VptDefect::AlignmentMismatch`] if `ptr` is not 8-byte aligned.
     /// - [`VptDefect::MagicMismatch`] if `header.magic` does not match [`VPT_MAGIC`].
     /// - [`VptDefect::VersionMismatch`] if `header.version` is not compatible with [`SDK_VERSION`].
@@ -216,14 +1141,19 @@ impl<'a> Vpt<'a> {
     ///
     /// # Safety
     ///
-    /// `ptr` must point to memory that is valid for reading up to `header.size` bytes.
+    /// `ptr` must point to memory that is valid for reading up to `header.size` bytes — plus,
+    /// when `header.flags` has [`VptFlags::SIGNED`] set, the `header.signature_len` bytes of
+    /// the trailing signature block. If the blob cannot be trusted to honor that, use
+    /// [`from_ptr_len`] and assert only the readable mapping's length.
+    ///
+    /// [`from_ptr_len`]: `Vpt::from_ptr_len`
     pub unsafe fn from_ptr(ptr: *const u8, vendor_id: u32) -> Result<Self, VptDefect> {
         let header_ptr = ptr as *const VptHeader;
         if !header_ptr.is_aligned() {
             return Err(VptDefect::AlignmentMismatch);
         }
 
-        let header = unsafe { &*header_ptr };
+        let header = unsafe { *header_ptr }.from_wire();
 
         if header.magic != VPT_MAGIC {
             return Err(VptDefect::MagicMismatch(header.magic));
@@ -234,66 +1164,1633 @@ impl<'a> Vpt<'a> {
         }
 
         if header.vendor_id != vendor_id {
-            return Err(VptDefect::VendorMismatch(header.vendor_id));
+            return Err(VptDefect::VendorMismatch {
+                expected: vendor_id,
+                found: header.vendor_id,
+            });
+        }
+
+        // A corrupt `size` smaller than the header itself would produce a `Vpt` whose `bytes`
+        // cannot contain its own header, panicking in `header()`.
+        if (header.size as usize) < size_of::<VptHeader>() {
+            return Err(VptDefect::SizeMismatch);
         }
 
+        let signature_len = if VptFlags(header.flags).contains(VptFlags::SIGNED) {
+            header.signature_len as usize
+        } else {
+            0
+        };
+        // two attacker-controlled u32s can overflow `usize` on 32-bit targets
+        let total_len = (header.size as usize)
+            .checked_add(signature_len)
+            .ok_or(VptDefect::SizeMismatch)?;
+        let bytes = unsafe { core::slice::from_raw_parts(ptr, total_len) };
+        let (table, signature) = bytes.split_at(header.size as usize);
+
         Ok(Self {
-            bytes: unsafe { core::slice::from_raw_parts(ptr, header.size as usize) },
+            bytes: table,
+            signature,
         })
     }
 
-    /// Returns the [`VptHeader`] of the VPT.
-    pub fn header(&self) -> &VptHeader {
-        bytemuck::from_bytes(&self.bytes[..size_of::<VptHeader>()])
+    /// Constructs a [`Vpt`] from a pointer and an explicit length bound.
+    ///
+    /// Unlike [`from_ptr`], which trusts `header.size` to decide how many bytes to read, this
+    /// constructor only reads within the `max_len` bytes the caller asserts are readable, so a
+    /// corrupt header cannot cause reads past the valid mapping.
+    ///
+    /// # Errors
+    ///
+    /// - [`VptDefect::SizeMismatch`] if `max_len` could not contain the entire VPT.
+    /// - [`VptDefect::AlignmentMismatch`] if `ptr` is not 8-byte aligned.
+    /// - [`VptDefect::MagicMismatch`] if `header.magic` does not match [`VPT_MAGIC`].
+    /// - [`VptDefect::VersionMismatch`] if `header.version` is not compatible with [`SDK_VERSION`].
+    /// - [`VptDefect::VendorMismatch`] if `header.vendor_id` does not match `vendor_id`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to memory that is valid for reading up to `max_len` bytes.
+    ///
+    /// [`from_ptr`]: `Vpt::from_ptr`
+    pub unsafe fn from_ptr_len(
+        ptr: *const u8,
+        max_len: usize,
+        vendor_id: u32,
+    ) -> Result<Self, VptDefect> {
+        Self::new(
+            unsafe { core::slice::from_raw_parts(ptr, max_len) },
+            vendor_id,
+        )
     }
 
-    /// Returns a [`ProgramIter`] which can be used to iterate through the programs within the VPT.
-    pub fn program_iter(&self) -> ProgramIter<'a> {
-        ProgramIter {
-            program_count: self.header().program_count,
-            current_program: 0,
-            bytes: &self.bytes[size_of::<VptHeader>()..],
-        }
+    /// Returns the number of programs the VPT's header claims to contain.
+    ///
+    /// A truncated blob may deliver fewer programs than this during iteration.
+    pub fn len(&self) -> u32 {
+        u32::from_le(self.header().program_count)
     }
-}
-
-impl<'a> Iterator for ProgramIter<'a> {
-    type Item = Program<'a>;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.current_program >= self.program_count {
-            return None;
-        }
-
-        let header_bytes = self.bytes.get(..size_of::<ProgramHeader>())?;
-        let header: &ProgramHeader = bytemuck::from_bytes(header_bytes);
+    /// Returns `true` if the VPT's header claims to contain no programs.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 
-        // program excluding header
-        let program = &self.bytes[size_of::<ProgramHeader>()..];
+    /// Returns the size in bytes the VPT's header declares, i.e. `header.size`.
+    ///
+    /// [`new`] trims the input slice down to this size, so it always equals
+    /// `self.as_bytes().len()` on a parsed table; its value lies in comparing against the length
+    /// of the raw input — see [`vpt_size_check`].
+    ///
+    /// [`new`]: `Vpt::new`
+    pub fn declared_size(&self) -> u32 {
+        u32::from_le(self.header().size)
+    }
 
-        let payload = program.get(..header.payload_len as usize)?;
-        let name = program.get(
-            header.payload_len as usize..header.payload_len as usize + header.name_len as usize,
-        )?;
+    /// Returns the size in bytes of the VPT's header.
+    ///
+    /// The program region starts at this offset; see [`programs_len_bytes`] for its size.
+    ///
+    /// [`programs_len_bytes`]: `Vpt::programs_len_bytes`
+    pub const fn header_len(&self) -> usize {
+        size_of::<VptHeader>()
+    }
 
-        let program_len =
-            size_of::<ProgramHeader>() + header.payload_len as usize + header.name_len as usize;
+    /// Returns the size in bytes of the program region: everything of the table past the header,
+    /// including any trailing name table.
+    pub fn programs_len_bytes(&self) -> usize {
+        self.declared_size() as usize - size_of::<VptHeader>()
+    }
 
-        self.bytes = &self.bytes[align8(program_len)..];
-        self.current_program += 1;
+    /// Returns the flags set in the VPT's header.
+    pub fn flags(&self) -> VptFlags {
+        VptFlags(u32::from_le(self.header().flags))
+    }
 
-        Some(Program { name, payload })
+    /// Returns the vendor ID recorded in the VPT's header.
+    pub fn vendor_id(&self) -> u32 {
+        u32::from_le(self.header().vendor_id)
     }
-}
 
-impl<'a> Program<'a> {
-    /// Returns the name of the program.
-    pub const fn name(&self) -> &'a [u8] {
-        self.name
+    /// Returns the spec version the VPT was built against.
+    pub fn version(&self) -> Version {
+        self.header().from_wire().version
     }
 
-    /// Returns the payload of the program.
-    pub const fn payload(&self) -> &'a [u8] {
+    /// Returns `true` if `self` and `other` are logically identical: same version, vendor ID,
+    /// and program count, with every program matching in name and payload, in table order.
+    ///
+    /// Unlike the derived [`PartialEq`], which compares raw blob bytes, this ignores
+    /// non-semantic differences — padding bytes a foreign tool left unzeroed, checksums, or
+    /// layout variants like [`VptFlags::NAME_TABLE`]. Unlike [`semantic_eq`], table order
+    /// matters.
+    ///
+    /// [`semantic_eq`]: `Vpt::semantic_eq`
+    pub fn logical_eq(&self, other: &Vpt<'_>) -> bool {
+        if self.version() != other.version()
+            || self.vendor_id() != other.vendor_id()
+            || self.len() != other.len()
+        {
+            return false;
+        }
+
+        self.program_iter()
+            .zip(other.program_iter())
+            .all(|(a, b)| a.name() == b.name() && a.payload() == b.payload())
+    }
+
+    /// Returns `true` if `self` and `other` contain the same programs, regardless of table
+    /// order.
+    ///
+    /// Programs are matched by name and compared by payload, so two VPTs produced by different
+    /// build orderings compare equal. The comparison is O(n²) but allocation-free, which suits
+    /// the small tables VPTs are built for. Tables with duplicate names may compare equal even
+    /// if the duplicates' payloads are permuted.
+    pub fn semantic_eq(&self, other: &Vpt<'_>) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        self.program_iter().all(|program| {
+            other
+                .program_by_name(program.name())
+                .is_some_and(|p| p.payload() == program.payload())
+        })
+    }
+
+    /// Returns the length of the VPT's largest payload in bytes, or 0 if it contains no
+    /// programs.
+    ///
+    /// Useful for sizing a scratch buffer that each program's payload is copied into in turn.
+    pub fn max_payload_len(&self) -> u32 {
+        self.program_iter()
+            .map(|program| program.payload_len() as u32)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the summed length of every payload in the VPT, in bytes.
+    pub fn total_payload_len(&self) -> u64 {
+        self.program_iter()
+            .map(|program| program.payload_len() as u64)
+            .sum()
+    }
+
+    /// Returns the validated bytes of the VPT, trimmed to `header.size`.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the trailing signature block, or [`None`] if the VPT is unsigned.
+    ///
+    /// Signed VPTs — those built with a signature, carrying [`VptFlags::SIGNED`] — store the
+    /// block immediately after the table's `header.size` bytes. The crate is crypto-agnostic:
+    /// callers verify these bytes against [`signed_bytes`] with their chosen algorithm.
+    ///
+    /// [`signed_bytes`]: `Vpt::signed_bytes`
+    pub fn signature(&self) -> Option<&'a [u8]> {
+        if self.flags().contains(VptFlags::SIGNED) {
+            Some(self.signature)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the region a signature covers: the entire table, header included.
+    pub const fn signed_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the program region of the blob: everything after the [`VptHeader`].
+    ///
+    /// This is the slice [`program_iter`] walks, exposed for callers building their own
+    /// iteration or indexing — parallel scanning, say — without re-deriving the header offset.
+    ///
+    /// [`program_iter`]: `Vpt::program_iter`
+    pub fn programs_bytes(&self) -> &'a [u8] {
+        self.bytes.get(size_of::<VptHeader>()..).unwrap_or(&[])
+    }
+
+    /// Returns the shared name table, or [`None`] if program names are stored inline.
+    ///
+    /// VPTs built with [`VptFlags::NAME_TABLE`] intern every program name into one contiguous
+    /// region occupying the last `header.name_table_len` bytes of the table, with each
+    /// `ProgramHeader::name_offset` pointing into it. [`Program::name`] resolves through the
+    /// table automatically; this accessor exposes the raw region for tooling.
+    pub fn name_table(&self) -> Option<&'a [u8]> {
+        if self.flags().contains(VptFlags::NAME_TABLE) {
+            let len = u32::from_le(self.header().name_table_len) as usize;
+            self.bytes.get(self.bytes.len().saturating_sub(len)..)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the [`VptHeader`] of the VPT.
+    pub fn header(&self) -> &VptHeader {
+        // the only slicing on the read side not routed through `get`: the type invariant
+        // guarantees `bytes` holds at least a header, and `header_checked` exists for callers
+        // who would rather re-check than rely on it
+        #[allow(clippy::indexing_slicing)]
+        bytemuck::from_bytes(&self.bytes[..size_of::<VptHeader>()])
+    }
+
+    /// Returns the [`VptHeader`] of the VPT, re-checking the blob's length and alignment instead
+    /// of relying on the invariant the constructors establish.
+    ///
+    /// Every safe constructor validates the header up front, so [`header`] cannot panic today.
+    /// Code that must stay panic-free even if a future `unsafe` constructor violates the
+    /// invariant — a watchdog task, say — can use this as defense in depth.
+    ///
+    /// # Errors
+    ///
+    /// - [`VptDefect::SizeMismatch`] if the blob is shorter than the header.
+    /// - [`VptDefect::AlignmentMismatch`] if the blob is not 8-byte aligned.
+    ///
+    /// [`header`]: `Vpt::header`
+    pub fn header_checked(&self) -> Result<&'a VptHeader, VptDefect> {
+        let bytes = self
+            .bytes
+            .get(..size_of::<VptHeader>())
+            .ok_or(VptDefect::SizeMismatch)?;
+
+        bytemuck::try_from_bytes(bytes).map_err(|err| match err {
+            PodCastError::AlignmentMismatch => VptDefect::AlignmentMismatch,
+            _ => VptDefect::SizeMismatch,
+        })
+    }
+
+    /// Walks the entire program table, verifying that every program the header claims is present
+    /// and within bounds.
+    ///
+    /// This offers an eager structural integrity gate without consuming an iterator: the blob
+    /// ending cleanly before `header.program_count` programs is reported separately from a
+    /// program overrunning the blob.
+    ///
+    /// # Errors
+    ///
+    /// - [`VptDefect::ProgramCountMismatch`] if the blob is exhausted before
+    ///   `header.program_count` programs are found.
+    /// - [`VptDefect::ProgramOutOfBounds`] if a program claims more bytes than exist in the blob.
+    pub fn validate(&self) -> Result<(), VptDefect> {
+        let expected = u32::from_le(self.header().program_count);
+        let mut found = 0;
+
+        let mut iter = self.program_iter();
+        loop {
+            match iter.try_next() {
+                Ok(Some(_)) => found += 1,
+                Ok(None) => return Ok(()),
+                // an empty remainder means the blob ended cleanly, just with too few programs
+                Err(_) if iter.bytes.is_empty() => {
+                    return Err(VptDefect::ProgramCountMismatch { expected, found });
+                }
+                Err(defect) => return Err(defect),
+            }
+        }
+    }
+
+    /// Returns a 64-bit FNV-1a hash of the validated blob, for caching and deduplication.
+    ///
+    /// Two VPTs with identical bytes always hash equally, so loaders can skip reprocessing
+    /// unchanged blobs. The hash is not cryptographic and must not be used to authenticate
+    /// untrusted VPTs.
+    pub const fn content_hash(&self) -> u64 {
+        let mut hash = 0xcbf29ce484222325u64;
+        let mut i = 0;
+        // indexing bounded by the loop condition; `const fn` rules out the iterator forms
+        #[allow(clippy::indexing_slicing)]
+        while i < self.bytes.len() {
+            hash ^= self.bytes[i] as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+            i += 1;
+        }
+        hash
+    }
+
+    /// Returns `true` if `header.checksum` matches the CRC32 of the bytes following the header.
+    pub fn verify_checksum(&self) -> bool {
+        u32::from_le(self.header().checksum)
+            == crc32::crc32(self.bytes.get(size_of::<VptHeader>()..).unwrap_or(&[]))
+    }
+
+    /// Returns the first program whose name equals `name`, or [`None`] if no program with that
+    /// name exists.
+    pub fn program_by_name(&self, name: &[u8]) -> Option<Program<'a>> {
+        self.program_iter().find(|program| program.name() == name)
+    }
+
+    /// Returns the first program whose name equals `name` ignoring ASCII case, or [`None`] if no
+    /// such program exists.
+    ///
+    /// The case-insensitive counterpart to [`program_by_name`], for names sourced from user
+    /// input or configuration files. Distinct names differing only in case match the first in
+    /// table order.
+    ///
+    /// [`program_by_name`]: `Vpt::program_by_name`
+    pub fn program_by_name_ignore_case(&self, name: &[u8]) -> Option<Program<'a>> {
+        self.program_iter()
+            .find(|program| program.name_eq_ignore_ascii_case(name))
+    }
+
+    /// Returns an iterator over the programs' names, in table order.
+    ///
+    /// For tooling that only lists names — populating a selection menu, say — this reads more
+    /// clearly than mapping over [`program_iter`] and avoids carrying the payload slices around.
+    ///
+    /// [`program_iter`]: `Vpt::program_iter`
+    pub fn names(&self) -> impl Iterator<Item = &'a [u8]> {
+        self.program_iter().map(|program| program.name())
+    }
+
+    /// Returns an iterator like [`names`], decoding each name as UTF-8 via [`Program::name_str`].
+    ///
+    /// [`names`]: `Vpt::names`
+    pub fn name_strs(&self) -> impl Iterator<Item = Result<&'a str, core::str::Utf8Error>> {
+        self.program_iter().map(|program| program.name_str())
+    }
+
+    /// Returns `true` if the VPT contains a program named `name`.
+    ///
+    /// Reads clearly in conditionals — `if vpt.contains_name(b"main")` — when the program itself
+    /// is not needed.
+    pub fn contains_name(&self, name: &[u8]) -> bool {
+        self.names().any(|n| n == name)
+    }
+
+    /// Returns `true` if the VPT contains a program whose name is the UTF-8 encoding of `name`.
+    pub fn contains_str(&self, name: &str) -> bool {
+        self.contains_name(name.as_bytes())
+    }
+
+    /// Returns an iterator over the programs owned by `vendor_id`, in table order.
+    ///
+    /// Programs without their own vendor — see [`Program::vendor_id`] — inherit the table's, so
+    /// on a single-vendor VPT this yields either every program or none.
+    pub fn programs_for_vendor(&self, vendor_id: u32) -> impl Iterator<Item = Program<'a>> {
+        let table_vendor = self.vendor_id();
+        self.program_iter()
+            .filter(move |program| program.vendor_id().unwrap_or(table_vendor) == vendor_id)
+    }
+
+    /// Returns an iterator over the programs whose names start with `prefix`, in table order.
+    ///
+    /// Useful with namespaced naming schemes like `ui/button` and `ui/slider`, where all
+    /// programs under `ui/` are wanted at once. The programs keep the blob's lifetime, and no
+    /// allocation takes place.
+    pub fn programs_with_prefix<'p>(
+        &self,
+        prefix: &'p [u8],
+    ) -> impl Iterator<Item = Program<'a>> + use<'a, 'p> {
+        self.program_iter()
+            .filter(move |program| program.name().starts_with(prefix))
+    }
+
+    /// Returns the first program whose name equals `name`, binary-searching the table when the
+    /// header has [`VptFlags::NAME_SORTED`] set.
+    ///
+    /// Falls back to the linear [`program_by_name`] if the table is unsorted or contains too many
+    /// programs to index.
+    ///
+    /// [`program_by_name`]: `Vpt::program_by_name`
+    pub fn program_by_name_sorted(&self, name: &[u8]) -> Option<Program<'a>> {
+        if !self.flags().contains(VptFlags::NAME_SORTED) {
+            return self.program_by_name(name);
+        }
+
+        let Ok(indexed) = self.indexed() else {
+            return self.program_by_name(name);
+        };
+
+        let mut lo = 0u32;
+        let mut hi = indexed.len() as u32;
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let program = indexed.program_at(mid)?;
+            match program.name().cmp(name) {
+                core::cmp::Ordering::Less => lo = mid + 1,
+                core::cmp::Ordering::Greater => hi = mid,
+                core::cmp::Ordering::Equal => return Some(program),
+            }
+        }
+
+        None
+    }
+
+    /// Returns the first program whose name equals `name`, or [`None`] if no program with that
+    /// name exists.
+    ///
+    /// This is a convenience wrapper around [`program_by_name`] for UTF-8 names.
+    ///
+    /// [`program_by_name`]: `Vpt::program_by_name`
+    pub fn program_by_str(&self, name: &str) -> Option<Program<'a>> {
+        self.program_by_name(name.as_bytes())
+    }
+
+    /// Returns the program at `index`, or [`None`] if `index` is out of bounds or the blob is
+    /// exhausted before reaching it.
+    ///
+    /// Programs are variable-length, so this scans sequentially from the start of the VPT.
+    pub fn program_at(&self, index: u32) -> Option<Program<'a>> {
+        self.program_iter().nth(index as usize)
+    }
+
+    /// Walks every program in table order, feeding each to `visitor`, in a single pass.
+    ///
+    /// Returns [`ControlFlow::Break`] if the visitor stopped the walk early, and
+    /// [`ControlFlow::Continue`] once the table is exhausted. See [`ProgramVisitor`] for when a
+    /// visitor beats separate queries.
+    ///
+    /// [`ControlFlow::Break`]: `core::ops::ControlFlow::Break`
+    /// [`ControlFlow::Continue`]: `core::ops::ControlFlow::Continue`
+    pub fn walk<V: ProgramVisitor<'a>>(&self, visitor: &mut V) -> core::ops::ControlFlow<()> {
+        for (index, program) in self.program_iter().enumerate() {
+            visitor.visit(index as u32, program)?;
+        }
+        core::ops::ControlFlow::Continue(())
+    }
+
+    /// Returns a [`ProgramIter`] which can be used to iterate through the programs within the VPT.
+    pub fn program_iter(&self) -> ProgramIter<'a> {
+        ProgramIter {
+            program_count: u32::from_le(self.header().program_count),
+            current_program: 0,
+            flags: self.flags(),
+            offset: size_of::<VptHeader>(),
+            bytes: self.programs_bytes(),
+            name_table: self.name_table().unwrap_or(&[]),
+            table: self.bytes,
+        }
+    }
+}
+
+/// Returns the exact byte size of a VPT containing programs with the given `(name_len,
+/// payload_len)` pairs.
+///
+/// This mirrors the size math of [`write_vpt`] and [`VptBuilder::build`] without requiring an
+/// allocator or the `builder` feature, so callers can plan buffer or flash reservations ahead of
+/// time. Being `const`, it can also size static buffers at compile time.
+///
+/// The result assumes no header flags that change the layout, such as
+/// [`VptFlags::PAYLOAD_ALIGN_16`], are set.
+pub const fn vpt_size(program_sizes: &[(usize, usize)]) -> usize {
+    let mut total_size = size_of::<VptHeader>();
+    let mut i = 0;
+    while i < program_sizes.len() {
+        let (name_len, payload_len) = program_sizes[i];
+        total_size += align8(size_of::<ProgramHeader>() + name_len + payload_len);
+        i += 1;
+    }
+    total_size
+}
+
+/// Checks `bytes.len()` against the size its VPT header declares, returning the declared size.
+///
+/// [`Vpt::new`] tolerates trailing bytes past `header.size` by silently trimming them, which is
+/// the right default for tables read out of larger flash regions but hides mismatches from
+/// tooling that expects the blob to be exact. This reads just the header — no program walk, no
+/// checksum — and reports the discrepancy precisely: too few bytes is
+/// [`VptDefect::SizeMismatch`], too many is [`VptDefect::TrailingBytes`] with the exact excess.
+/// A signature trailing a [`VptFlags::SIGNED`] table counts toward the expected length, not the
+/// excess.
+///
+/// # Errors
+///
+/// - [`VptDefect::SizeMismatch`] if `bytes` is shorter than the declared size (or the header
+///   itself).
+/// - [`VptDefect::AlignmentMismatch`] if `bytes` is not 8-byte aligned.
+/// - [`VptDefect::MagicMismatch`] if `header.magic` does not match [`VPT_MAGIC`].
+/// - [`VptDefect::TrailingBytes`] if `bytes` extends past the declared size.
+pub fn vpt_size_check(bytes: &[u8]) -> Result<u32, VptDefect> {
+    let header_bytes = bytes
+        .get(..size_of::<VptHeader>())
+        .ok_or(VptDefect::SizeMismatch)?;
+
+    let header = bytemuck::try_from_bytes::<VptHeader>(header_bytes)
+        .map_err(|_| VptDefect::AlignmentMismatch)?
+        .from_wire();
+
+    if header.magic != VPT_MAGIC {
+        return Err(VptDefect::MagicMismatch(header.magic));
+    }
+
+    let mut expected = header.size as usize;
+    if VptFlags(header.flags).contains(VptFlags::SIGNED) {
+        expected = expected.saturating_add(header.signature_len as usize);
+    }
+
+    match bytes.len() {
+        len if len < expected => Err(VptDefect::SizeMismatch),
+        len if len > expected => Err(VptDefect::TrailingBytes {
+            extra: bytes.len() - expected,
+        }),
+        _ => Ok(header.size),
+    }
+}
+
+/// Parses a [`VptHeader`] out of `bytes` field by field, with no alignment requirement.
+///
+/// [`Vpt::new`] casts the header in place via `bytemuck`, which demands the blob be 8-byte
+/// aligned. This reads each field with [`u32::from_le_bytes`] from its wire offset instead, so
+/// it works on any alignment — tooling peeking at a header mid-file, say — and makes the
+/// little-endian decoding explicit. The returned header is in native order; [`from_wire`] has
+/// already been applied, so its fields can be read directly on any target.
+///
+/// Only the magic is validated; the rest of the header is returned as stored. Parse the full
+/// blob with [`Vpt::new`] (or [`Vpt::new_aligned`] for unaligned bytes) to validate it.
+///
+/// # Errors
+///
+/// - [`VptDefect::SizeMismatch`] if `bytes` is shorter than a header.
+/// - [`VptDefect::MagicMismatch`] if `header.magic` does not match [`VPT_MAGIC`].
+///
+/// [`from_wire`]: `VptHeader::from_wire`
+pub fn parse_header(bytes: &[u8]) -> Result<VptHeader, VptDefect> {
+    use core::mem::offset_of;
+
+    let field = |offset: usize| {
+        bytes
+            .get(offset..offset + size_of::<u32>())
+            .and_then(|field| field.try_into().ok())
+            .map(u32::from_le_bytes)
+            .ok_or(VptDefect::SizeMismatch)
+    };
+
+    let magic = field(offset_of!(VptHeader, magic))?;
+    if magic != VPT_MAGIC {
+        return Err(VptDefect::MagicMismatch(magic));
+    }
+
+    Ok(VptHeader {
+        magic,
+        version: Version {
+            major: field(offset_of!(VptHeader, version))?,
+            minor: field(offset_of!(VptHeader, version) + size_of::<u32>())?,
+        },
+        vendor_id: field(offset_of!(VptHeader, vendor_id))?,
+        size: field(offset_of!(VptHeader, size))?,
+        program_count: field(offset_of!(VptHeader, program_count))?,
+        checksum: field(offset_of!(VptHeader, checksum))?,
+        flags: field(offset_of!(VptHeader, flags))?,
+        signature_len: field(offset_of!(VptHeader, signature_len))?,
+        name_table_len: field(offset_of!(VptHeader, name_table_len))?,
+    })
+}
+
+/// Writes a VPT containing `programs` into `buf`, returning the number of bytes written.
+///
+/// Each entry of `programs` is a `(name, payload)` pair. Unlike [`VptBuilder`], this function
+/// requires no allocator, making it usable from embedded callers assembling a VPT in static
+/// memory or on the stack. `buf` does not need to be 8-byte aligned, but the resulting VPT can
+/// only be parsed in place if it is.
+///
+/// # Errors
+///
+/// - [`BuildError::BufferTooSmall`] if `buf` cannot contain the entire VPT.
+pub fn write_vpt(
+    buf: &mut [u8],
+    vendor_id: u32,
+    programs: &[(&[u8], &[u8])],
+) -> Result<usize, BuildError> {
+    let total_size = size_of::<VptHeader>()
+        + programs
+            .iter()
+            .map(|(name, payload)| align8(size_of::<ProgramHeader>() + name.len() + payload.len()))
+            .sum::<usize>();
+
+    if buf.len() < total_size {
+        return Err(BuildError::BufferTooSmall {
+            required: total_size,
+            available: buf.len(),
+        });
+    }
+
+    let mut cursor = size_of::<VptHeader>();
+    for (name, payload) in programs {
+        let header = ProgramHeader {
+            name_len: name.len() as u32,
+            payload_len: payload.len() as u32,
+            compression: Compression::None.as_raw(),
+            uncompressed_len: payload.len() as u32,
+            kind: ProgramKind::Executable.as_raw(),
+            payload_digest: 0,
+            vendor_id: 0,
+            name_offset: 0,
+            payload_prepad: 0,
+            payload_offset: 0,
+            meta_len: 0,
+            reserved: 0,
+        }
+        .to_wire();
+
+        buf[cursor..cursor + size_of::<ProgramHeader>()]
+            .copy_from_slice(bytemuck::bytes_of(&header));
+        cursor += size_of::<ProgramHeader>();
+
+        buf[cursor..cursor + payload.len()].copy_from_slice(payload);
+        cursor += payload.len();
+
+        buf[cursor..cursor + name.len()].copy_from_slice(name);
+        cursor += name.len();
+
+        let padded = align8(cursor);
+        buf[cursor..padded].fill(0);
+        cursor = padded;
+    }
+
+    let header = VptHeader {
+        magic: VPT_MAGIC,
+        version: SDK_VERSION,
+        vendor_id,
+        size: total_size as u32,
+        program_count: programs.len() as u32,
+        checksum: crc32::crc32(&buf[size_of::<VptHeader>()..total_size]),
+        flags: 0,
+        signature_len: 0,
+        name_table_len: 0,
+    }
+    .to_wire();
+    buf[..size_of::<VptHeader>()].copy_from_slice(bytemuck::bytes_of(&header));
+
+    Ok(total_size)
+}
+
+/// Converts a byte slice into a [`Vpt`] without checking the vendor ID.
+///
+/// This conversion runs all of [`Vpt::new`]'s checks except the vendor comparison, accepting a
+/// VPT from any vendor. Callers that care about the vendor can inspect `header().vendor_id`
+/// afterward, or use [`Vpt::new`] to enforce a specific vendor up front.
+impl<'a> TryFrom<&'a [u8]> for Vpt<'a> {
+    type Error = VptDefect;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::new_any_vendor(bytes)
+    }
+}
+
+impl<'a> IntoIterator for &Vpt<'a> {
+    type Item = Program<'a>;
+    type IntoIter = ProgramIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.program_iter()
+    }
+}
+
+/// Program iterator obtained from [`ProgramIter::with_offsets`], yielding each program together
+/// with the byte offset of its header within the original blob.
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OffsetProgramIter<'a> {
+    inner: ProgramIter<'a>,
+}
+
+impl<'a> Iterator for OffsetProgramIter<'a> {
+    type Item = (usize, Program<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.inner.offset;
+        Some((offset, self.inner.next()?))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl ExactSizeIterator for OffsetProgramIter<'_> {}
+
+#[deny(clippy::indexing_slicing)]
+impl<'a> ProgramIter<'a> {
+    /// Converts the iterator into one that also yields the byte offset of each program's header,
+    /// relative to the start of the original blob.
+    ///
+    /// The offset accounts for the [`VptHeader`] prefix and the alignment padding between
+    /// programs, which the iterator already tracks internally.
+    pub const fn with_offsets(self) -> OffsetProgramIter<'a> {
+        OffsetProgramIter { inner: self }
+    }
+
+    /// Returns the number of programs the header claims are left to yield.
+    ///
+    /// The same upper bound [`size_hint`] reports, exposed directly for progress reporting
+    /// during a long load — "loading 3 of 10" — without consuming the iterator. A truncated
+    /// blob may deliver fewer.
+    ///
+    /// [`size_hint`]: `Iterator::size_hint`
+    pub const fn remaining(&self) -> u32 {
+        self.program_count - self.current_program
+    }
+
+    /// Returns the program [`next`] would yield, without advancing the iterator.
+    ///
+    /// Lookahead-based dispatch — inspecting the next program's name to decide whether to
+    /// consume it — wants to read ahead without committing. The iterator is [`Copy`], so this
+    /// parses from a scratch copy and leaves `self` untouched.
+    ///
+    /// [`next`]: `Iterator::next`
+    pub fn peek(&self) -> Option<Program<'a>> {
+        let mut copy = *self;
+        copy.next()
+    }
+
+    /// Advances the iterator until it finds a program named `name`, returning it, or exhausts
+    /// the table, returning [`None`].
+    ///
+    /// Unlike [`Vpt::program_by_name`], the iterator is left positioned after the match, so the
+    /// caller can keep iterating from there — useful for jumping ahead in a processing loop
+    /// without collecting or tracking indices.
+    pub fn skip_to_name(&mut self, name: &[u8]) -> Option<Program<'a>> {
+        self.find(|program| program.name() == name)
+    }
+
+    /// Advances past one program reading only its header, without extracting name and payload
+    /// slices. Returns [`None`] if the table is exhausted or the program is malformed.
+    fn skip_one(&mut self) -> Option<()> {
+        if self.current_program >= self.program_count {
+            return None;
+        }
+
+        // mirror `try_next`'s leading alignment padding
+        if self.flags.contains(VptFlags::PAYLOAD_ALIGN_16)
+            && self.offset % 16 != ALIGN_16_HEADER_REM
+        {
+            self.bytes = self.bytes.get(8..).unwrap_or(&[]);
+            self.offset += 8;
+        }
+
+        let header_bytes = self.bytes.get(..size_of::<ProgramHeader>())?;
+        debug_assert!(header_bytes.as_ptr().cast::<ProgramHeader>().is_aligned());
+        let header: &ProgramHeader = bytemuck::from_bytes(header_bytes);
+        let native = header.from_wire();
+
+        // a shared payload lives elsewhere in the blob, contributing no on-disk bytes here
+        let shared_payload =
+            self.flags.contains(VptFlags::PAYLOAD_SHARED) && native.payload_offset != 0;
+        let inline_payload_len = if shared_payload {
+            0
+        } else {
+            native.payload_len as usize
+        };
+
+        let on_disk = size_of::<ProgramHeader>().checked_add(native.payload_prepad as usize)?;
+        let program_len = if self.flags.contains(VptFlags::NAME_TABLE) {
+            // the name lives in the shared table, so only the payload and metadata contribute
+            // on-disk bytes
+            let len = on_disk
+                .checked_add(inline_payload_len)?
+                .checked_add(native.meta_len as usize)?;
+            if len > self.bytes.len() {
+                return None;
+            }
+            len
+        } else {
+            let len = on_disk
+                .checked_add(inline_payload_len)?
+                .checked_add(native.name_len as usize)?
+                .checked_add(native.meta_len as usize)?;
+            if len > self.bytes.len() {
+                return None;
+            }
+            len
+        };
+
+        // mirror `try_next`'s out-of-line bounds checks, so a program it would report as
+        // malformed also terminates iteration when skipped over
+        if self.flags.contains(VptFlags::NAME_TABLE) {
+            let start = native.name_offset as usize;
+            start
+                .checked_add(native.name_len as usize)
+                .and_then(|end| self.name_table.get(start..end))?;
+        }
+        if shared_payload {
+            let start = native.payload_offset as usize;
+            start
+                .checked_add(native.payload_len as usize)
+                .and_then(|end| self.table.get(start..end))?;
+        }
+
+        let advance = align8(program_len);
+
+        self.bytes = self.bytes.get(advance..).unwrap_or(&[]);
+        self.offset += advance;
+        self.current_program += 1;
+
+        Some(())
+    }
+
+    /// Advances the iterator like [`next`], reporting malformed programs as errors instead of
+    /// silently terminating.
+    ///
+    /// Returns `Ok(None)` once all `header.program_count` programs have been yielded, making
+    /// corruption distinguishable from normal exhaustion.
+    ///
+    /// # Errors
+    ///
+    /// - [`VptDefect::ProgramOutOfBounds`] if the next program's header is truncated or its
+    ///   length fields overflow. The error carries the program's index and the byte offset of
+    ///   its header within the blob.
+    /// - [`VptDefect::PayloadOutOfBounds`] if the program's payload overruns the blob.
+    /// - [`VptDefect::NameOutOfBounds`] if the program's name overruns the blob — or, under
+    ///   [`VptFlags::NAME_TABLE`], the shared name table — which usually means `name_len` was
+    ///   miscomputed while `payload_len` is fine.
+    ///
+    /// [`next`]: `Iterator::next`
+    pub fn try_next(&mut self) -> Result<Option<Program<'a>>, VptDefect> {
+        if self.current_program >= self.program_count {
+            return Ok(None);
+        }
+
+        // under `PAYLOAD_ALIGN_16`, 8 padding bytes precede any header whose natural offset
+        // would leave the payload misaligned — the first program's included
+        if self.flags.contains(VptFlags::PAYLOAD_ALIGN_16)
+            && self.offset % 16 != ALIGN_16_HEADER_REM
+        {
+            self.bytes = self.bytes.get(8..).unwrap_or(&[]);
+            self.offset += 8;
+        }
+
+        let defect = VptDefect::ProgramOutOfBounds {
+            index: self.current_program,
+            offset: self.offset,
+        };
+
+        let header_bytes = self.bytes.get(..size_of::<ProgramHeader>()).ok_or(defect)?;
+        // `align8` advances and the aligned blob base keep every header sub-slice 8-aligned; the
+        // `from_bytes` below relies on it, so guard the assumption on debug builds
+        debug_assert!(
+            header_bytes.as_ptr().cast::<ProgramHeader>().is_aligned(),
+            "program header at offset {} is misaligned",
+            self.offset
+        );
+        let header: &ProgramHeader = bytemuck::from_bytes(header_bytes);
+        let native = header.from_wire();
+
+        // a shared payload lives elsewhere in the blob, contributing no on-disk bytes here
+        let shared_payload =
+            self.flags.contains(VptFlags::PAYLOAD_SHARED) && native.payload_offset != 0;
+        let inline_payload_len = if shared_payload {
+            0
+        } else {
+            native.payload_len as usize
+        };
+
+        // pre-payload padding counts toward the program's bytes; `validate` rejects a non-zero
+        // `payload_prepad` overrunning the blob on the inline path, and the table path checks it
+        let payload_start = size_of::<ProgramHeader>()
+            .checked_add(native.payload_prepad as usize)
+            .ok_or(defect)?;
+
+        let (name, program_len) = if self.flags.contains(VptFlags::NAME_TABLE) {
+            // the name lives in the shared table, so only the payload is bounds-checked inline
+            let len = payload_start
+                .checked_add(inline_payload_len)
+                .ok_or(defect)?;
+            if len > self.bytes.len() {
+                return Err(VptDefect::PayloadOutOfBounds {
+                    index: self.current_program,
+                });
+            }
+
+            let start = native.name_offset as usize;
+            let name = start
+                .checked_add(native.name_len as usize)
+                .and_then(|end| self.name_table.get(start..end))
+                .ok_or(VptDefect::NameOutOfBounds {
+                    index: self.current_program,
+                })?;
+
+            (name, len)
+        } else if shared_payload {
+            // the payload is bounds-checked against the table below; only the name is inline
+            let len = payload_start
+                .checked_add(native.name_len as usize)
+                .ok_or(defect)?;
+            if len > self.bytes.len() {
+                return Err(VptDefect::NameOutOfBounds {
+                    index: self.current_program,
+                });
+            }
+
+            let name = self.bytes.get(payload_start..len).ok_or(defect)?;
+            (name, len)
+        } else {
+            // restore the table position that `ProgramHeader::validate` cannot know
+            header.validate(self.bytes.len()).map_err(|e| match e {
+                VptDefect::PayloadOutOfBounds { .. } => VptDefect::PayloadOutOfBounds {
+                    index: self.current_program,
+                },
+                VptDefect::NameOutOfBounds { .. } => VptDefect::NameOutOfBounds {
+                    index: self.current_program,
+                },
+                _ => defect,
+            })?;
+
+            let name_start = payload_start + native.payload_len as usize;
+            let len = name_start + native.name_len as usize;
+            // `validate` just confirmed the program fits, so the lookup cannot fail
+            let name = self.bytes.get(name_start..len).ok_or(defect)?;
+
+            (name, len)
+        };
+
+        // the metadata string trails the inline name (or the payload, when names are interned);
+        // `meta_len` must be zero unless `VptFlags::PROGRAM_METADATA` is set, so it is accounted
+        // for unconditionally, like `payload_prepad`
+        let meta_end = program_len
+            .checked_add(native.meta_len as usize)
+            .ok_or(defect)?;
+        let metadata = self.bytes.get(program_len..meta_end).ok_or(defect)?;
+        let program_len = meta_end;
+
+        // resolve the payload: shared payloads index into the full table region, inline ones
+        // follow the header directly (`program_len` was just bounds-checked)
+        let (payload, payload_offset) = if shared_payload {
+            let start = native.payload_offset as usize;
+            let payload = start
+                .checked_add(native.payload_len as usize)
+                .and_then(|end| self.table.get(start..end))
+                .ok_or(VptDefect::PayloadOutOfBounds {
+                    index: self.current_program,
+                })?;
+            (payload, start)
+        } else {
+            let payload = self
+                .bytes
+                .get(payload_start..payload_start + native.payload_len as usize)
+                .ok_or(VptDefect::PayloadOutOfBounds {
+                    index: self.current_program,
+                })?;
+            (payload, self.offset + payload_start)
+        };
+
+        let advance = align8(program_len);
+
+        self.bytes = self.bytes.get(advance..).unwrap_or(&[]);
+        self.offset += advance;
+        self.current_program += 1;
+
+        Ok(Some(Program {
+            header,
+            flags: self.flags,
+            name,
+            payload,
+            metadata,
+            payload_offset,
+        }))
+    }
+}
+
+#[deny(clippy::indexing_slicing)]
+impl<'a> Iterator for ProgramIter<'a> {
+    type Item = Program<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.try_next().unwrap_or(None)
+    }
+
+    // the default implementation would slice name and payload for every skipped program just to
+    // discard them; skipping on headers alone is cheaper
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        for _ in 0..n {
+            self.skip_one()?;
+        }
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.program_count - self.current_program) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+/// The length reported by this impl is derived from `header.program_count` and is an upper bound:
+/// a truncated blob may yield fewer programs than the header claims, in which case the iterator
+/// terminates early.
+impl ExactSizeIterator for ProgramIter<'_> {}
+
+/// Exhaustion and corruption are both terminal: [`next`] never advances past a malformed
+/// program, so once it returns [`None`] — whether all programs were yielded or a defect was hit
+/// — every later call returns [`None`] too. Adapters relying on fused semantics, like
+/// `by_ref().chain(..)`, behave correctly.
+///
+/// [`next`]: `Iterator::next`
+impl core::iter::FusedIterator for ProgramIter<'_> {}
+
+impl core::iter::FusedIterator for OffsetProgramIter<'_> {}
+
+/// A visitor over a VPT's programs, driven by [`Vpt::walk`].
+///
+/// A loader answering several questions about a table — counting programs, finding the largest
+/// payload, locating a name — would otherwise scan once per question. A visitor accumulates
+/// arbitrary state in a single pass and can stop early via [`ControlFlow::Break`]. The trait is
+/// implemented for any `FnMut(u32, Program<'a>) -> ControlFlow<()>` closure; [`NameFinder`] and
+/// [`ProgramStats`] cover the common cases.
+///
+/// [`ControlFlow::Break`]: `core::ops::ControlFlow::Break`
+pub trait ProgramVisitor<'a> {
+    /// Visits one program at `index`, returning whether the walk should continue.
+    fn visit(&mut self, index: u32, program: Program<'a>) -> core::ops::ControlFlow<()>;
+}
+
+impl<'a, F: FnMut(u32, Program<'a>) -> core::ops::ControlFlow<()>> ProgramVisitor<'a> for F {
+    fn visit(&mut self, index: u32, program: Program<'a>) -> core::ops::ControlFlow<()> {
+        self(index, program)
+    }
+}
+
+/// A [`ProgramVisitor`] that searches for a program by exact name, stopping at the first match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NameFinder<'n, 'a> {
+    name: &'n [u8],
+    /// The first matching program and its index, or [`None`] if the walk found no match.
+    pub found: Option<(u32, Program<'a>)>,
+}
+
+impl<'n> NameFinder<'n, '_> {
+    /// Constructs a finder for the given name.
+    pub const fn new(name: &'n [u8]) -> Self {
+        Self { name, found: None }
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for NameFinder<'_, 'a> {
+    fn visit(&mut self, index: u32, program: Program<'a>) -> core::ops::ControlFlow<()> {
+        if program.name() == self.name {
+            self.found = Some((index, program));
+            core::ops::ControlFlow::Break(())
+        } else {
+            core::ops::ControlFlow::Continue(())
+        }
+    }
+}
+
+/// A [`ProgramVisitor`] that accumulates size statistics over every program it visits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ProgramStats {
+    /// Number of programs visited.
+    pub programs: u32,
+    /// Sum of the visited programs' payload lengths in bytes.
+    pub payload_bytes: usize,
+    /// Largest payload length seen, in bytes.
+    pub max_payload_len: usize,
+}
+
+impl ProgramVisitor<'_> for ProgramStats {
+    fn visit(&mut self, _index: u32, program: Program<'_>) -> core::ops::ControlFlow<()> {
+        self.programs += 1;
+        self.payload_bytes += program.payload_len();
+        self.max_payload_len = self.max_payload_len.max(program.payload_len());
+        core::ops::ControlFlow::Continue(())
+    }
+}
+
+#[deny(clippy::indexing_slicing)]
+impl<'a> Program<'a> {
+    /// Returns the name of the program.
+    pub const fn name(&self) -> &'a [u8] {
+        self.name
+    }
+
+    /// Returns the length of the program's name in bytes.
+    pub const fn name_len(&self) -> usize {
+        self.name.len()
+    }
+
+    /// Returns `true` if the program's name equals `name`, ignoring ASCII case.
+    ///
+    /// For user-typed or configuration-sourced names, where `Main` should match `main`. Exact
+    /// comparison via [`name`] remains the default for internal references.
+    ///
+    /// [`name`]: `Program::name`
+    pub const fn name_eq_ignore_ascii_case(&self, name: &[u8]) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+    }
+
+    /// Returns the length of the program's payload in bytes.
+    pub const fn payload_len(&self) -> usize {
+        self.payload.len()
+    }
+
+    /// Returns the program's metadata string — a human-readable description for menus — or
+    /// [`None`] if the table does not carry metadata or this program has none.
+    ///
+    /// Metadata is descriptive only: lookups key on [`name`], so two programs may share a
+    /// description. Present when [`VptFlags::PROGRAM_METADATA`] is set and the program's
+    /// `meta_len` is nonzero.
+    ///
+    /// [`name`]: `Program::name`
+    pub const fn metadata(&self) -> Option<&'a [u8]> {
+        if self.flags.contains(VptFlags::PROGRAM_METADATA) && !self.metadata.is_empty() {
+            Some(self.metadata)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of bytes the program occupies in the blob, including its header and
+    /// alignment padding.
+    ///
+    /// This is the read-side equivalent of [`ProgramBuilder::size`].
+    ///
+    /// [`ProgramBuilder::size`]: `crate::ProgramBuilder::size`
+    pub const fn on_disk_size(&self) -> usize {
+        // under `NAME_TABLE` the name lives in the shared table, not in the program's bytes
+        let inline_name_len = if self.flags.contains(VptFlags::NAME_TABLE) {
+            0
+        } else {
+            self.name.len()
+        };
+        // a shared payload lives in another program's bytes, not in this one's
+        let inline_payload_len = if self.payload_is_shared() {
+            0
+        } else {
+            self.payload.len()
+        };
+        // `payload_prepad` must be zero unless `PAYLOAD_PREPAD` is set
+        let prepad = u32::from_le(self.header.payload_prepad) as usize;
+        align8(
+            size_of::<ProgramHeader>()
+                + prepad
+                + inline_name_len
+                + inline_payload_len
+                + self.metadata.len(),
+        )
+    }
+
+    /// Returns `true` if the program's payload is stored in another program's bytes under
+    /// [`VptFlags::PAYLOAD_SHARED`], rather than inline after its own header.
+    ///
+    /// [`payload`] resolves the reference either way; this only distinguishes where the bytes
+    /// live, for tooling that reports layout or per-program sizes.
+    ///
+    /// [`payload`]: `Program::payload`
+    pub const fn payload_is_shared(&self) -> bool {
+        self.flags.contains(VptFlags::PAYLOAD_SHARED)
+            && u32::from_le(self.header.payload_offset) != 0
+    }
+
+    /// Returns the byte offset of the payload within the blob it was parsed from.
+    ///
+    /// Under [`VptFlags::PAYLOAD_PREPAD`], payloads sit at stricter alignments than the
+    /// format's 8 bytes; this exposes the resulting position for callers that map or execute
+    /// the payload in place.
+    pub const fn payload_offset(&self) -> usize {
+        self.payload_offset
+    }
+
+    /// Returns the kind of module the payload carries, or [`None`] if the value is reserved for
+    /// a future kind.
+    pub const fn kind(&self) -> Option<ProgramKind> {
+        ProgramKind::from_raw(u32::from_le(self.header.kind))
+    }
+
+    /// Returns the compression codec applied to the payload, or [`None`] if the codec is unknown
+    /// or its cargo feature is disabled.
+    pub const fn compression(&self) -> Option<Compression> {
+        Compression::from_raw(u32::from_le(self.header.compression))
+    }
+
+    /// Returns the length of the payload in bytes once decompressed.
+    ///
+    /// Equal to [`payload_len`] when the payload is uncompressed.
+    ///
+    /// [`payload_len`]: `Program::payload_len`
+    pub const fn uncompressed_len(&self) -> usize {
+        u32::from_le(self.header.uncompressed_len) as usize
+    }
+
+    /// Decompresses the payload into `buf`, returning the number of bytes written.
+    ///
+    /// [`payload`] always returns the raw bytes as stored in the blob; decompression is explicit
+    /// so that callers control where the decompressed image lives. For uncompressed payloads this
+    /// is a plain copy.
+    ///
+    /// # Errors
+    ///
+    /// - [`DecompressError::UnsupportedCodec`] if the codec is unknown or its cargo feature is
+    ///   disabled.
+    /// - [`DecompressError::BufferTooSmall`] if `buf` cannot hold [`uncompressed_len`] bytes.
+    /// - [`DecompressError::Corrupt`] if the compressed payload is malformed, or an
+    ///   uncompressed payload is longer than its header's [`uncompressed_len`] claims.
+    ///
+    /// [`payload`]: `Program::payload`
+    /// [`uncompressed_len`]: `Program::uncompressed_len`
+    pub fn decompress_into(&self, buf: &mut [u8]) -> Result<usize, DecompressError> {
+        let required = self.uncompressed_len();
+        if buf.len() < required {
+            return Err(DecompressError::BufferTooSmall {
+                required,
+                available: buf.len(),
+            });
+        }
+
+        match self.compression() {
+            Some(Compression::None) => {
+                // a corrupt header can claim an `uncompressed_len` below the stored payload's
+                // length; `buf` was only checked against the claim, so bound the copy
+                let target = buf
+                    .get_mut(..self.payload.len())
+                    .ok_or(DecompressError::Corrupt)?;
+                target.copy_from_slice(self.payload);
+                Ok(self.payload.len())
+            }
+            #[cfg(feature = "lz4")]
+            Some(Compression::Lz4) => {
+                // the buffer check above guarantees `required` bytes are available
+                let target = buf.get_mut(..required).ok_or(DecompressError::Corrupt)?;
+                lz4_flex::block::decompress_into(self.payload, target)
+                    .map_err(|_| DecompressError::Corrupt)
+            }
+            None => Err(DecompressError::UnsupportedCodec(u32::from_le(
+                self.header.compression,
+            ))),
+        }
+    }
+
+    /// Returns the largest power-of-two alignment that the payload's address satisfies.
+    ///
+    /// Loaders mapping executable payloads in place can use this to verify the payload meets
+    /// their alignment requirement; see [`VptFlags::PAYLOAD_ALIGN_16`].
+    pub fn payload_alignment(&self) -> usize {
+        1 << (self.payload.as_ptr() as usize)
+            .trailing_zeros()
+            .min(usize::BITS - 1)
+    }
+
+    /// Returns the name of the program as a UTF-8 string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Utf8Error`] if the name is not valid UTF-8.
+    ///
+    /// [`Utf8Error`]: `core::str::Utf8Error`
+    pub const fn name_str(&self) -> Result<&'a str, core::str::Utf8Error> {
+        core::str::from_utf8(self.name)
+    }
+
+    /// Returns the payload of the program.
+    pub const fn payload(&self) -> &'a [u8] {
         self.payload
     }
+
+    /// Returns the program's own vendor ID, or [`None`] if it inherits the table's.
+    ///
+    /// Per-program vendors exist when the VPT was built with [`VptFlags::PROGRAM_VENDORS`]; a
+    /// recorded vendor of zero also means inheritance. Use [`Vpt::programs_for_vendor`] to
+    /// resolve inheritance against the table's vendor.
+    pub const fn vendor_id(&self) -> Option<u32> {
+        if self.flags.contains(VptFlags::PROGRAM_VENDORS)
+            && u32::from_le(self.header.vendor_id) != 0
+        {
+            Some(u32::from_le(self.header.vendor_id))
+        } else {
+            None
+        }
+    }
+
+    /// Verifies the program's payload against the CRC32 digest in its header, or returns `true`
+    /// if the VPT carries no per-program digests.
+    ///
+    /// Digests are present when the VPT was built with [`VptFlags::PAYLOAD_DIGEST`]. They cover
+    /// the payload as stored in the blob — before decompression — so a loader can verify just
+    /// the one module it is about to run without checksumming the whole VPT via
+    /// [`Vpt::verify_checksum`].
+    pub const fn verify_payload(&self) -> bool {
+        if !self.flags.contains(VptFlags::PAYLOAD_DIGEST) {
+            return true;
+        }
+
+        crc32::crc32(self.payload) == u32::from_le(self.header.payload_digest)
+    }
+
+    /// Returns a copy of the program's on-disk header.
+    pub const fn raw_header(&self) -> ProgramHeader {
+        *self.header
+    }
+
+    /// Decomposes the program into its on-disk header and zero-copy `(name, payload)` slices.
+    ///
+    /// Useful for re-serializing a program or verifying round-trip encoding without re-deriving
+    /// lengths at the call site.
+    pub const fn as_raw_parts(&self) -> (ProgramHeader, &'a [u8], &'a [u8]) {
+        (*self.header, self.name, self.payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[repr(align(8))]
+    struct Aligned<const N: usize>([u8; N]);
+
+    fn header_with_size(size: u32) -> VptHeader {
+        VptHeader {
+            magic: VPT_MAGIC,
+            version: SDK_VERSION,
+            vendor_id: 0,
+            size,
+            program_count: 0,
+            checksum: 0,
+            flags: 0,
+            signature_len: 0,
+            name_table_len: 0,
+        }
+        .to_wire()
+    }
+
+    #[test]
+    fn new_rejects_size_smaller_than_header() {
+        let mut blob = Aligned([0u8; size_of::<VptHeader>()]);
+        blob.0
+            .copy_from_slice(bytemuck::bytes_of(&header_with_size(4)));
+
+        assert_eq!(Vpt::new(&blob.0, 0), Err(VptDefect::SizeMismatch));
+    }
+
+    #[test]
+    fn from_ptr_rejects_size_smaller_than_header() {
+        let mut blob = Aligned([0u8; size_of::<VptHeader>()]);
+        blob.0
+            .copy_from_slice(bytemuck::bytes_of(&header_with_size(4)));
+
+        assert_eq!(
+            unsafe { Vpt::from_ptr(blob.0.as_ptr(), 0) },
+            Err(VptDefect::SizeMismatch)
+        );
+    }
+
+    #[test]
+    fn iter_rejects_overflowing_program_lengths() {
+        const SIZE: usize = size_of::<VptHeader>() + size_of::<ProgramHeader>();
+
+        let mut blob = Aligned([0u8; SIZE]);
+        let mut header = header_with_size(SIZE as u32);
+        header.program_count = 1u32.to_le();
+        blob.0[..size_of::<VptHeader>()].copy_from_slice(bytemuck::bytes_of(&header));
+        // `payload_len + name_len` wraps `usize` on 32-bit targets; on 64-bit targets the sum
+        // merely exceeds the blob. Either way the program must be reported as out of bounds, not
+        // yielded with garbage slices.
+        blob.0[size_of::<VptHeader>()..].copy_from_slice(bytemuck::bytes_of(
+            &ProgramHeader {
+                name_len: u32::MAX,
+                payload_len: u32::MAX,
+                compression: 0,
+                uncompressed_len: 0,
+                kind: 0,
+                payload_digest: 0,
+                vendor_id: 0,
+                name_offset: 0,
+                payload_prepad: 0,
+                payload_offset: 0,
+                meta_len: 0,
+                reserved: 0,
+            }
+            .to_wire(),
+        ));
+
+        let vpt = Vpt::new(&blob.0, 0).unwrap();
+        let mut iter = vpt.program_iter();
+        // 32-bit targets report the wrapping sum as `ProgramOutOfBounds`; 64-bit targets get past
+        // the sum and report the payload overrunning the blob
+        assert!(matches!(
+            iter.try_next(),
+            Err(VptDefect::ProgramOutOfBounds { .. } | VptDefect::PayloadOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn iter_rejects_overflowing_payload_prepad() {
+        const SIZE: usize = size_of::<VptHeader>() + size_of::<ProgramHeader>();
+
+        let mut blob = Aligned([0u8; SIZE]);
+        let mut header = header_with_size(SIZE as u32);
+        header.program_count = 1u32.to_le();
+        blob.0[..size_of::<VptHeader>()].copy_from_slice(bytemuck::bytes_of(&header));
+        // `size_of::<ProgramHeader>() + payload_prepad` wraps `usize` on 32-bit targets; on
+        // 64-bit targets the padding merely overruns the blob. Either way the program must be
+        // reported as out of bounds, not yielded with garbage slices.
+        blob.0[size_of::<VptHeader>()..].copy_from_slice(bytemuck::bytes_of(
+            &ProgramHeader {
+                name_len: 0,
+                payload_len: 0,
+                compression: 0,
+                uncompressed_len: 0,
+                kind: 0,
+                payload_digest: 0,
+                vendor_id: 0,
+                name_offset: 0,
+                payload_prepad: u32::MAX,
+                payload_offset: 0,
+                meta_len: 0,
+                reserved: 0,
+            }
+            .to_wire(),
+        ));
+
+        let vpt = Vpt::new(&blob.0, 0).unwrap();
+        let mut iter = vpt.program_iter();
+        assert!(matches!(
+            iter.try_next(),
+            Err(VptDefect::ProgramOutOfBounds { .. } | VptDefect::PayloadOutOfBounds { .. })
+        ));
+    }
+
+    // the zero-copy reads in `program_iter` are only sound if `align8` padding keeps every
+    // program header 8-aligned; exercise length combinations straddling the rounding boundaries
+    // on a blob at a known-aligned base, as on the armv7a-vex-v5 target itself
+    #[test]
+    fn program_headers_stay_aligned_across_length_combinations() {
+        let payload = [0xabu8; 16];
+        let programs: [(&[u8], &[u8]); 7] = [
+            (b"a", &payload[..0]),
+            (b"ab", &payload[..1]),
+            (b"abcdefg", &payload[..7]),
+            (b"abcdefgh", &payload[..8]),
+            (b"abcdefghi", &payload[..9]),
+            (b"x", &payload[..15]),
+            (b"xy", &payload[..16]),
+        ];
+
+        let mut blob = Aligned([0u8; 512]);
+        let len = write_vpt(&mut blob.0, 0, &programs).unwrap();
+
+        let vpt = Vpt::new(&blob.0[..len], 0).unwrap();
+        let mut count = 0;
+        for (offset, program) in vpt.program_iter().with_offsets() {
+            assert_eq!(offset % VPT_ALIGNMENT, 0);
+            assert_eq!(program.name(), programs[count].0);
+            assert_eq!(program.payload(), programs[count].1);
+            count += 1;
+        }
+        assert_eq!(count, programs.len());
+    }
+
+    #[test]
+    fn iteration_is_fused_past_exhaustion_and_corruption() {
+        // exhaustion: a header-only blob keeps yielding `None`
+        let mut blob = Aligned([0u8; size_of::<VptHeader>()]);
+        blob.0.copy_from_slice(bytemuck::bytes_of(&header_with_size(
+            size_of::<VptHeader>() as u32,
+        )));
+        let vpt = Vpt::new(&blob.0, 0).unwrap();
+        let mut iter = vpt.program_iter();
+        for _ in 0..3 {
+            assert_eq!(iter.next(), None);
+        }
+
+        // corruption: a program overrunning the blob is terminal, not skippable
+        const SIZE: usize = size_of::<VptHeader>() + size_of::<ProgramHeader>();
+        let mut blob = Aligned([0u8; SIZE]);
+        let mut header = header_with_size(SIZE as u32);
+        header.program_count = 1u32.to_le();
+        blob.0[..size_of::<VptHeader>()].copy_from_slice(bytemuck::bytes_of(&header));
+        blob.0[size_of::<VptHeader>()..].copy_from_slice(bytemuck::bytes_of(
+            &ProgramHeader {
+                name_len: 0,
+                payload_len: u32::MAX,
+                compression: 0,
+                uncompressed_len: 0,
+                kind: 0,
+                payload_digest: 0,
+                vendor_id: 0,
+                name_offset: 0,
+                payload_prepad: 0,
+                payload_offset: 0,
+                meta_len: 0,
+                reserved: 0,
+            }
+            .to_wire(),
+        ));
+        let vpt = Vpt::new(&blob.0, 0).unwrap();
+        let mut iter = vpt.program_iter();
+        for _ in 0..3 {
+            assert_eq!(iter.next(), None);
+        }
+    }
+
+    // runs on both endiannesses: the serialized bytes are little-endian by contract, and the
+    // parser must interpret them the same way everywhere
+    #[test]
+    fn headers_serialize_little_endian() {
+        let header = VptHeader::new(7, size_of::<VptHeader>() as u32, 0);
+        let bytes = header.to_bytes();
+        assert_eq!(bytes[..4], VPT_MAGIC.to_le_bytes());
+
+        let mut blob = Aligned([0u8; size_of::<VptHeader>()]);
+        blob.0.copy_from_slice(&bytes);
+
+        let vpt = Vpt::new(&blob.0, 7).unwrap();
+        assert_eq!(vpt.vendor_id(), 7);
+        assert_eq!(vpt.version(), SDK_VERSION);
+        assert_eq!(vpt.len(), 0);
+    }
+
+    // error reporting must work on the bare-metal V5 target, where `alloc` may be unavailable:
+    // every defect message has to render through `core::fmt::Write` into fixed storage
+    #[test]
+    fn defect_messages_format_into_fixed_buffer() {
+        use core::fmt::Write;
+
+        struct StackBuf {
+            bytes: [u8; 128],
+            len: usize,
+        }
+
+        impl Write for StackBuf {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                let end = self.len + s.len();
+                self.bytes
+                    .get_mut(self.len..end)
+                    .ok_or(core::fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        let defects: &[(VptDefect, &str)] = &[
+            (
+                VptDefect::SizeMismatch,
+                "VPT blob longer than provided bytes",
+            ),
+            (VptDefect::AlignmentMismatch, "VPT blob not 8-byte aligned"),
+            (
+                VptDefect::MagicMismatch(0xdead_beef),
+                "incorrect magic: expected 0x675c3ed9, found 0xdeadbeef",
+            ),
+            (
+                VptDefect::VendorMismatch {
+                    expected: 0x7,
+                    found: 0x42,
+                },
+                "vendor ID mismatch: expected 0x00000007, found 0x00000042",
+            ),
+            (
+                VptDefect::ChecksumMismatch {
+                    expected: 1,
+                    computed: 2,
+                },
+                "checksum mismatch: expected 0x00000001, computed 0x00000002",
+            ),
+            (
+                VptDefect::ProgramOutOfBounds {
+                    index: 3,
+                    offset: 40,
+                },
+                "program 3 at offset 40 out of bounds",
+            ),
+            (
+                VptDefect::PayloadOutOfBounds { index: 4 },
+                "payload of program 4 out of bounds",
+            ),
+            (
+                VptDefect::NameOutOfBounds { index: 5 },
+                "name of program 5 out of bounds",
+            ),
+            (
+                VptDefect::ProgramCountMismatch {
+                    expected: 6,
+                    found: 2,
+                },
+                "program count mismatch: header claims 6 programs, found 2",
+            ),
+            (
+                VptDefect::TooLarge {
+                    size: 512,
+                    limit: 256,
+                },
+                "VPT of 512 bytes exceeds limit of 256 bytes",
+            ),
+            (
+                VptDefect::TrailingBytes { extra: 8 },
+                "8 trailing bytes past the VPT's declared size",
+            ),
+            (
+                VptDefect::VersionMismatch(Version { major: 9, minor: 9 }),
+                "incompatible version",
+            ),
+            (
+                VptDefect::VersionTooOld {
+                    found: Version { major: 0, minor: 8 },
+                    required: Version { major: 0, minor: 9 },
+                },
+                "version too old: VPT version 0.8 does not satisfy required minimum 0.9",
+            ),
+        ];
+
+        for (defect, expected) in defects {
+            let mut buf = StackBuf {
+                bytes: [0; 128],
+                len: 0,
+            };
+            write!(buf, "{defect}").unwrap();
+            let rendered = core::str::from_utf8(&buf.bytes[..buf.len]).unwrap();
+            assert!(
+                rendered.starts_with(expected),
+                "unexpected message for {defect:?}: {rendered:?}"
+            );
+        }
+    }
 }
@@ -0,0 +1,199 @@
+//! Mutable VPT views.
+//!
+//! Loaders sometimes patch program payloads in place — applying relocations, stamping runtime
+//! addresses — before execution. [`VptMut`] validates a blob exactly like [`Vpt`] but borrows it
+//! mutably, handing out [`ProgramMut`]s whose payload bytes can be rewritten. Names and headers
+//! stay immutable, and lengths cannot change: that would require relaying out the blob.
+
+use crate::{ALIGN_16_HEADER_REM, ProgramHeader, Vpt, VptDefect, VptFlags, VptHeader, align8};
+
+/// A zero-copy mutable view of a Venice Program Table.
+///
+/// Validation is identical to [`Vpt::new`]; the only difference is that the blob is borrowed
+/// mutably so program payloads can be patched in place via [`for_each_program_mut`].
+///
+/// [`for_each_program_mut`]: `VptMut::for_each_program_mut`
+#[derive(Debug, PartialEq, Eq)]
+pub struct VptMut<'a> {
+    // Invariant: same as `Vpt::bytes` — a well-aligned VPT with a valid header, trimmed to
+    // `header.size`.
+    bytes: &'a mut [u8],
+}
+
+/// A program of a [`VptMut`], with mutable access to its payload bytes.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ProgramMut<'p> {
+    header: ProgramHeader,
+    name: &'p [u8],
+    payload: &'p mut [u8],
+}
+
+impl<'a> VptMut<'a> {
+    /// Validates and constructs a mutable VPT view from a blob of bytes.
+    ///
+    /// # Errors
+    ///
+    /// Identical to [`Vpt::new`].
+    pub fn new(bytes: &'a mut [u8], vendor_id: u32) -> Result<Self, VptDefect> {
+        let size = Vpt::new(bytes, vendor_id)?.as_bytes().len();
+        Ok(Self {
+            bytes: &mut bytes[..size],
+        })
+    }
+
+    /// Returns the VPT's header.
+    pub fn header(&self) -> &VptHeader {
+        bytemuck::from_bytes(&self.bytes[..size_of::<VptHeader>()])
+    }
+
+    /// Reborrows the view as an immutable [`Vpt`].
+    pub fn as_vpt(&self) -> Vpt<'_> {
+        // the invariant on `bytes` is exactly `Vpt`'s, so no revalidation is needed
+        // a signature following the table is not tracked by the mutable view
+        Vpt {
+            bytes: self.bytes,
+            signature: &[],
+        }
+    }
+
+    /// Returns the bytes of the VPT, consuming the view.
+    pub fn into_bytes(self) -> &'a mut [u8] {
+        self.bytes
+    }
+
+    /// Calls `f` with a [`ProgramMut`] for each program, in table order.
+    ///
+    /// Mutable iteration through an `Iterator` impl would require the iterator to hand out
+    /// borrows outliving `next`, which the borrow checker cannot express for this layout, so
+    /// in-place patching uses this callback form instead. Like [`ProgramIter`], iteration stops
+    /// early at the first malformed program.
+    ///
+    /// Under [`VptFlags::PAYLOAD_SHARED`], a program referencing a shared payload is yielded
+    /// with an empty payload slice: the bytes live in the program that stores them inline, and
+    /// handing out a second mutable borrow would alias it. Patch shared bytes through their
+    /// storing program.
+    ///
+    /// [`ProgramIter`]: `crate::ProgramIter`
+    pub fn for_each_program_mut(&mut self, mut f: impl FnMut(ProgramMut<'_>)) {
+        let header = self.header().from_wire();
+        let flags = VptFlags::from_bits(header.flags);
+
+        // split the shared name table off the end so names can be resolved while the program
+        // region is mutably borrowed
+        let table_len = if flags.contains(VptFlags::NAME_TABLE) {
+            header.name_table_len as usize
+        } else {
+            0
+        };
+        let split = self.bytes.len() - table_len;
+        let (region, name_table) = self.bytes.split_at_mut(split);
+        let name_table: &[u8] = name_table;
+
+        let mut offset = size_of::<VptHeader>();
+        let mut rest = &mut region[size_of::<VptHeader>()..];
+
+        for _ in 0..header.program_count {
+            // mirror `ProgramIter::try_next`'s leading alignment padding
+            if flags.contains(VptFlags::PAYLOAD_ALIGN_16) && offset % 16 != ALIGN_16_HEADER_REM {
+                if rest.len() < 8 {
+                    return;
+                }
+                rest = &mut core::mem::take(&mut rest)[8..];
+                offset += 8;
+            }
+
+            if rest.len() < size_of::<ProgramHeader>() {
+                return;
+            }
+
+            let (header_bytes, body) =
+                core::mem::take(&mut rest).split_at_mut(size_of::<ProgramHeader>());
+            let program_header = bytemuck::from_bytes::<ProgramHeader>(header_bytes).from_wire();
+
+            let inline_name_len = if flags.contains(VptFlags::NAME_TABLE) {
+                0
+            } else {
+                program_header.name_len as usize
+            };
+            // a shared payload lives in another program's bytes; this program's body holds none
+            let inline_payload_len =
+                if flags.contains(VptFlags::PAYLOAD_SHARED) && program_header.payload_offset != 0 {
+                    0
+                } else {
+                    program_header.payload_len as usize
+                };
+            let Some(body_len) = (program_header.payload_prepad as usize)
+                .checked_add(inline_payload_len)
+                .and_then(|n| n.checked_add(inline_name_len))
+                .and_then(|n| n.checked_add(program_header.meta_len as usize))
+            else {
+                return;
+            };
+            if body.len() < body_len {
+                return;
+            }
+
+            let (_prepad, body) = body.split_at_mut(program_header.payload_prepad as usize);
+            let (payload, name_and_tail) = body.split_at_mut(inline_payload_len);
+            let (inline_name, tail) = name_and_tail.split_at_mut(inline_name_len);
+            // metadata stays immutable; skip past it to keep the advance math aligned
+            let (_metadata, tail) = tail.split_at_mut(program_header.meta_len as usize);
+
+            let name: &[u8] = if flags.contains(VptFlags::NAME_TABLE) {
+                let start = program_header.name_offset as usize;
+                let Some(name) = start
+                    .checked_add(program_header.name_len as usize)
+                    .and_then(|end| name_table.get(start..end))
+                else {
+                    return;
+                };
+                name
+            } else {
+                inline_name
+            };
+
+            f(ProgramMut {
+                header: program_header,
+                name,
+                payload,
+            });
+
+            // mirror `ProgramIter::try_next`'s advance, measured from the start of the program
+            let program_len = size_of::<ProgramHeader>() + body_len;
+            let advance = align8(program_len);
+
+            let padding = (advance - program_len).min(tail.len());
+            rest = &mut tail[padding..];
+            offset += advance;
+        }
+    }
+}
+
+impl<'p> ProgramMut<'p> {
+    /// Returns the name of the program.
+    pub const fn name(&self) -> &[u8] {
+        self.name
+    }
+
+    /// Returns a copy of the program's on-disk header.
+    pub const fn raw_header(&self) -> ProgramHeader {
+        self.header
+    }
+
+    /// Returns the payload of the program.
+    pub const fn payload(&self) -> &[u8] {
+        self.payload
+    }
+
+    /// Returns the payload of the program for in-place mutation.
+    ///
+    /// The payload's length is fixed; changing it would require relaying out the blob.
+    pub const fn payload_mut(&mut self) -> &mut [u8] {
+        self.payload
+    }
+
+    /// Consumes the view, returning the payload with the full borrow lifetime.
+    pub const fn into_payload_mut(self) -> &'p mut [u8] {
+        self.payload
+    }
+}
@@ -0,0 +1,233 @@
+//! Owned forms of the zero-copy VPT types.
+//!
+//! This module can be used by enabling the `alloc` feature, with the condition that a memory
+//! allocator is available to the program.
+
+use alloc::vec::Vec;
+
+use crate::{Program, Version, Vpt, VptDefect};
+
+/// An owned form of [`Program`], holding its name and payload in [`Vec<u8>`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedProgram {
+    /// Name of the program.
+    pub name: Vec<u8>,
+    /// Payload of the program.
+    pub payload: Vec<u8>,
+}
+
+/// An owned form of [`Vpt`], holding its programs in a [`Vec`] detached from the original blob.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedVpt {
+    /// VPT version.
+    pub version: Version,
+    /// Vendor ID.
+    pub vendor_id: u32,
+    /// Programs contained within the VPT.
+    pub programs: Vec<OwnedProgram>,
+}
+
+impl From<Program<'_>> for OwnedProgram {
+    fn from(program: Program<'_>) -> Self {
+        Self {
+            name: program.name().to_vec(),
+            payload: program.payload().to_vec(),
+        }
+    }
+}
+
+/// An owned, comparison-friendly snapshot of a VPT's logical content, obtained from
+/// [`Vpt::snapshot`].
+///
+/// Test assertions against a parsed table become a single `assert_eq!` between two snapshots —
+/// or a snapshot and a literal — instead of hand-rolled iteration and slice comparison. The
+/// [`Debug`] output renders names as ASCII and payloads as hex, keeping golden-file diffs
+/// readable for binary content.
+///
+/// [`Debug`]: `core::fmt::Debug`
+#[derive(Clone, PartialEq, Eq)]
+pub struct VptSnapshot {
+    /// Vendor ID recorded in the header.
+    pub vendor_id: u32,
+    /// VPT version recorded in the header.
+    pub version: Version,
+    /// The programs' `(name, payload)` pairs, in table order.
+    pub programs: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl core::fmt::Debug for VptSnapshot {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        struct Name<'a>(&'a [u8]);
+        impl core::fmt::Debug for Name<'_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "\"{}\"", self.0.escape_ascii())
+            }
+        }
+
+        struct Hex<'a>(&'a [u8]);
+        impl core::fmt::Debug for Hex<'_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("0x")?;
+                for byte in self.0 {
+                    write!(f, "{byte:02x}")?;
+                }
+                Ok(())
+            }
+        }
+
+        struct Programs<'a>(&'a [(Vec<u8>, Vec<u8>)]);
+        impl core::fmt::Debug for Programs<'_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_list()
+                    .entries(
+                        self.0
+                            .iter()
+                            .map(|(name, payload)| (Name(name), Hex(payload))),
+                    )
+                    .finish()
+            }
+        }
+
+        f.debug_struct("VptSnapshot")
+            .field("vendor_id", &self.vendor_id)
+            .field("version", &self.version)
+            .field("programs", &Programs(&self.programs))
+            .finish()
+    }
+}
+
+/// An owned copy of a VPT blob that can hand back a borrowed [`Vpt`], obtained from
+/// [`Vpt::to_buf`].
+///
+/// Unlike [`OwnedVpt`], which restructures the table into [`Vec`]s of programs, `VptBuf` keeps
+/// the raw blob byte-for-byte, so [`borrow`] is free. The bytes are held in 8-byte-aligned
+/// storage, and the copy was validated when the source [`Vpt`] was parsed, so `borrow` cannot
+/// produce an invalid table.
+///
+/// [`borrow`]: `VptBuf::borrow`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VptBuf {
+    // Invariant: the first `len` bytes of `words` are a copy of a validated VPT blob. `Vec<u64>`
+    // rather than `Vec<u8>` so the blob stays 8-byte aligned.
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl VptBuf {
+    /// Returns the bytes of the blob.
+    pub fn as_bytes(&self) -> &[u8] {
+        &bytemuck::cast_slice(&self.words)[..self.len]
+    }
+
+    /// Borrows the blob as a [`Vpt`].
+    pub fn borrow(&self) -> Vpt<'_> {
+        // the invariant on `words` carries `Vpt`'s forward: the bytes were validated when the
+        // source was parsed, and `Vec<u64>` keeps them 8-byte aligned
+        // `VptBuf` stores the table region only, so a signature trailing the source blob is not
+        // carried over
+        Vpt {
+            bytes: self.as_bytes(),
+            signature: &[],
+        }
+    }
+}
+
+impl<'a> Vpt<'a> {
+    /// Collects every program into a [`Vec`] pre-sized to `header.program_count`.
+    ///
+    /// Equivalent to `self.program_iter().collect()`, but guarantees the `Vec` is allocated
+    /// exactly once up front — useful when indexing large tables, where repeated growth would
+    /// dominate. The programs stay zero-copy views into the blob.
+    pub fn collect_programs(&self) -> Vec<Program<'a>> {
+        let mut programs = Vec::with_capacity(self.len() as usize);
+        programs.extend(self.program_iter());
+        programs
+    }
+
+    /// Collects the programs' names into a [`Vec`] sorted lexicographically, for display.
+    ///
+    /// Menus and tooling output usually want names alphabetized regardless of table order; this
+    /// sorts a list of borrowed names without touching the underlying blob. The names keep the
+    /// blob's lifetime.
+    pub fn program_names_sorted(&self) -> Vec<&'a [u8]> {
+        let mut names: Vec<&'a [u8]> = self.names().collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Collects the programs into a [`Vec`] sorted lexicographically by name, like
+    /// [`program_names_sorted`] but keeping the payloads at hand.
+    ///
+    /// [`program_names_sorted`]: `Vpt::program_names_sorted`
+    pub fn programs_sorted(&self) -> Vec<Program<'a>> {
+        let mut programs = self.collect_programs();
+        programs.sort_unstable_by(|a, b| a.name().cmp(b.name()));
+        programs
+    }
+
+    /// Captures the VPT's logical content as an owned, `assert_eq!`-friendly [`VptSnapshot`].
+    pub fn snapshot(&self) -> VptSnapshot {
+        VptSnapshot {
+            vendor_id: self.vendor_id(),
+            version: self.version(),
+            programs: self
+                .program_iter()
+                .map(|program| (program.name().to_vec(), program.payload().to_vec()))
+                .collect(),
+        }
+    }
+
+    /// Copies the VPT into an [`OwnedVpt`] detached from the original blob.
+    pub fn to_owned(&self) -> OwnedVpt {
+        OwnedVpt {
+            version: self.version(),
+            vendor_id: self.vendor_id(),
+            programs: self.program_iter().map(OwnedProgram::from).collect(),
+        }
+    }
+
+    /// Copies the VPT's bytes into an owned [`Vec<u8>`].
+    ///
+    /// The copy has no alignment guarantee; to keep an owned blob that can be re-borrowed as a
+    /// [`Vpt`], use [`to_buf`] instead.
+    ///
+    /// [`to_buf`]: `Vpt::to_buf`
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    /// Validates a possibly misaligned blob by copying it into owned, 8-byte-aligned storage.
+    ///
+    /// [`Vpt::new`] rejects blobs at arbitrary addresses — inside a larger packet buffer, say —
+    /// with [`VptDefect::AlignmentMismatch`]. This constructor instead copies the bytes into a
+    /// fresh 8-byte-aligned allocation before validating, trading away the zero-copy guarantee
+    /// for one copy. Callers who control the blob's placement should prefer [`Vpt::new`].
+    ///
+    /// # Errors
+    ///
+    /// All errors returned by [`Vpt::new`] except [`VptDefect::AlignmentMismatch`].
+    pub fn new_aligned(bytes: &[u8], vendor_id: u32) -> Result<VptBuf, VptDefect> {
+        let mut words = alloc::vec![0u64; bytes.len().div_ceil(size_of::<u64>())];
+        bytemuck::cast_slice_mut::<u64, u8>(&mut words)[..bytes.len()].copy_from_slice(bytes);
+
+        let len = Vpt::new(&bytemuck::cast_slice(&words)[..bytes.len()], vendor_id)?
+            .as_bytes()
+            .len();
+
+        Ok(VptBuf { words, len })
+    }
+
+    /// Copies the VPT's bytes into a [`VptBuf`] detached from the original blob.
+    pub fn to_buf(&self) -> VptBuf {
+        let bytes = self.as_bytes();
+        let mut words = alloc::vec![0u64; bytes.len().div_ceil(size_of::<u64>())];
+        bytemuck::cast_slice_mut(&mut words)[..bytes.len()].copy_from_slice(bytes);
+
+        VptBuf {
+            words,
+            len: bytes.len(),
+        }
+    }
+}
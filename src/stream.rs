@@ -0,0 +1,118 @@
+//! Streaming VPT writer.
+//!
+//! This module can be used by enabling the `std` feature, which provides the [`Write`] and
+//! [`Seek`] traits the writer is built on.
+
+use std::io::{Seek, SeekFrom, Write};
+
+use crate::{
+    Compression, ProgramHeader, ProgramKind, SDK_VERSION, VPT_MAGIC, VptHeader, align8, crc32,
+};
+
+/// A writer that streams VPT bytes incrementally to an [`io::Write`] sink.
+///
+/// Unlike [`VptBuilder`], which buffers the entire table in a [`Vec<u8>`] before flushing, this
+/// writer emits each program as it is added, keeping peak memory proportional to one program
+/// rather than the whole table. The header is written as a placeholder up front and patched on
+/// [`finish`], so the sink must also implement [`Seek`].
+///
+/// [`io::Write`]: `std::io::Write`
+/// [`VptBuilder`]: `crate::VptBuilder`
+/// [`Vec<u8>`]: `alloc::vec::Vec`
+/// [`finish`]: `VptStreamWriter::finish`
+#[derive(Debug)]
+pub struct VptStreamWriter<W: Write + Seek> {
+    sink: W,
+    vendor_id: u32,
+    // stream position of the header, for patching on finish
+    header_pos: u64,
+    // bytes written after the header
+    written: usize,
+    program_count: u32,
+    // incremental CRC32 state over all bytes after the header
+    checksum: u32,
+}
+
+impl<W: Write + Seek> VptStreamWriter<W> {
+    /// Constructs a writer targeting `sink`, writing a placeholder header at the current stream
+    /// position.
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by the sink.
+    pub fn new(mut sink: W, vendor_id: u32) -> std::io::Result<Self> {
+        let header_pos = sink.stream_position()?;
+        sink.write_all(&[0u8; size_of::<VptHeader>()])?;
+
+        Ok(Self {
+            sink,
+            vendor_id,
+            header_pos,
+            written: 0,
+            program_count: 0,
+            checksum: crc32::INIT,
+        })
+    }
+
+    /// Streams a program to the sink.
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by the sink.
+    pub fn add_program(&mut self, name: &[u8], payload: &[u8]) -> std::io::Result<()> {
+        let header = ProgramHeader {
+            name_len: name.len() as u32,
+            payload_len: payload.len() as u32,
+            compression: Compression::None.as_raw(),
+            uncompressed_len: payload.len() as u32,
+            kind: ProgramKind::Executable.as_raw(),
+            payload_digest: 0,
+            vendor_id: 0,
+            name_offset: 0,
+            payload_prepad: 0,
+            payload_offset: 0,
+            meta_len: 0,
+            reserved: 0,
+        }
+        .to_wire();
+
+        let base_size = size_of::<ProgramHeader>() + payload.len() + name.len();
+        let padding = [0u8; 7];
+        let padding = &padding[..align8(base_size) - base_size];
+
+        for part in [bytemuck::bytes_of(&header), payload, name, padding] {
+            self.sink.write_all(part)?;
+            self.checksum = crc32::update(self.checksum, part);
+        }
+
+        self.written += align8(base_size);
+        self.program_count += 1;
+        Ok(())
+    }
+
+    /// Patches the header with the final size, program count, and checksum, returning the sink
+    /// positioned at the end of the VPT.
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by the sink.
+    pub fn finish(mut self) -> std::io::Result<W> {
+        let header = VptHeader {
+            magic: VPT_MAGIC,
+            version: SDK_VERSION,
+            vendor_id: self.vendor_id,
+            size: (size_of::<VptHeader>() + self.written) as u32,
+            program_count: self.program_count,
+            checksum: crc32::finalize(self.checksum),
+            flags: 0,
+            signature_len: 0,
+            name_table_len: 0,
+        }
+        .to_wire();
+
+        self.sink.seek(SeekFrom::Start(self.header_pos))?;
+        self.sink.write_all(bytemuck::bytes_of(&header))?;
+        self.sink.seek(SeekFrom::End(0))?;
+        Ok(self.sink)
+    }
+}